@@ -92,6 +92,14 @@ fn benchmark_round_trip(c: &mut Criterion) {
     });
 }
 
+fn benchmark_to_prose(c: &mut Criterion) {
+    let aisp = "∀x∈S:x≥0∧x≤10⇒valid(x); ∃!y∈ℕ:y≜|S|; f:ℤ→𝔹; P(A∣B)≥80%";
+
+    c.bench_function("to_prose", |b| {
+        b.iter(|| RosettaStone::to_prose(black_box(aisp)));
+    });
+}
+
 criterion_group!(
     benches,
     benchmark_minimal_conversion,
@@ -100,5 +108,6 @@ criterion_group!(
     benchmark_tier_detection,
     benchmark_rosetta_lookup,
     benchmark_round_trip,
+    benchmark_to_prose,
 );
 criterion_main!(benches);
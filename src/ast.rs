@@ -0,0 +1,494 @@
+//! AISP syntax tree - structured output for programmatic consumers
+//!
+//! Conversion produces a string; `AispDocument` parses that string into
+//! blocks, quantified statements, and operator trees, and renders back to the
+//! exact same text through `Display`.
+
+use crate::converter::{AispConverter, ConversionOptions};
+use crate::rosetta::ROSETTA;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// Table symbols that can start a token, longest first
+    ///
+    /// Purely alphanumeric symbols ("Pre", "List") read as identifiers.
+    static ref TOKEN_SYMBOLS: Vec<&'static str> = {
+        let mut symbols: Vec<_> = ROSETTA
+            .iter()
+            .map(|entry| entry.symbol)
+            .filter(|symbol| !symbol.chars().all(|c| c.is_ascii_alphanumeric()))
+            .collect();
+        symbols.sort_by_key(|symbol| std::cmp::Reverse(symbol.len()));
+        symbols.dedup();
+        symbols
+    };
+}
+
+/// Infix operators and their binding strength (higher binds tighter)
+const INFIX_OPERATORS: &[(&str, u8)] = &[
+    ("≜", 1),
+    ("≔", 1),
+    ("↦", 1),
+    ("→", 1),
+    ("⇒", 2),
+    ("⇔", 2),
+    ("↔", 2),
+    ("∨", 3),
+    ("⊕", 3),
+    ("∧", 4),
+    ("∈", 5),
+    ("∉", 5),
+    ("⊆", 5),
+    ("⊑", 5),
+    ("⊇", 5),
+    ("⊂", 5),
+    ("⊃", 5),
+    ("≡", 5),
+    ("≢", 5),
+    ("≈", 5),
+    (">", 5),
+    ("<", 5),
+    ("≥", 5),
+    ("≤", 5),
+    ("∪", 6),
+    ("∩", 6),
+    ("∖", 6),
+    ("+", 6),
+    ("−", 6),
+    ("×", 7),
+    ("÷", 7),
+];
+
+/// Symbols that open a `Q binding:body` statement
+const QUANTIFIERS: &[&str] = &["∀", "∃", "∃!", "∄", "¬∀"];
+
+/// A node in a parsed AISP document
+#[derive(Debug, Clone, PartialEq)]
+pub enum AispNode {
+    /// A table symbol that is not an infix operator ("ℤ", "∅", "¬")
+    Symbol(String),
+    /// A name ("x", "user_count")
+    Identifier(String),
+    /// A block such as `⟦Ω:Meta⟧{...}` or `⟦Ε⟧⟨...⟩`
+    Block {
+        /// Text between `⟦` and `⟧` ("Ω:Meta")
+        name: String,
+        /// Opening and closing delimiters of the body
+        delimiters: (char, char),
+        /// Body contents
+        children: Vec<AispNode>,
+    },
+    /// Two operands joined by an infix operator with no space between them
+    BinaryOp {
+        op: String,
+        left: Box<AispNode>,
+        right: Box<AispNode>,
+    },
+    /// A quantified statement such as `∀x∈S:x>0`
+    Quantified {
+        /// Quantifier symbol ("∀", "∃!")
+        quantifier: String,
+        /// Bound variables, before the `:`
+        binding: Vec<AispNode>,
+        /// Statement, after the `:`
+        body: Vec<AispNode>,
+    },
+    /// Numbers, quoted strings, punctuation, and whitespace, kept verbatim
+    Literal(String),
+}
+
+impl std::fmt::Display for AispNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AispNode::Symbol(text) | AispNode::Identifier(text) | AispNode::Literal(text) => {
+                write!(f, "{}", text)
+            }
+            AispNode::Block {
+                name,
+                delimiters: (open, close),
+                children,
+            } => {
+                write!(f, "⟦{}⟧{}", name, open)?;
+                write_nodes(f, children)?;
+                write!(f, "{}", close)
+            }
+            AispNode::BinaryOp { op, left, right } => write!(f, "{}{}{}", left, op, right),
+            AispNode::Quantified {
+                quantifier,
+                binding,
+                body,
+            } => {
+                write!(f, "{}", quantifier)?;
+                write_nodes(f, binding)?;
+                write!(f, ":")?;
+                write_nodes(f, body)
+            }
+        }
+    }
+}
+
+fn write_nodes(f: &mut std::fmt::Formatter<'_>, nodes: &[AispNode]) -> std::fmt::Result {
+    nodes.iter().try_for_each(|node| write!(f, "{}", node))
+}
+
+/// A parsed AISP document; `Display` renders the original text
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AispDocument {
+    /// Top-level nodes in document order
+    pub nodes: Vec<AispNode>,
+}
+
+impl std::fmt::Display for AispDocument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_nodes(f, &self.nodes)
+    }
+}
+
+impl AispDocument {
+    /// Parse AISP text into a tree
+    ///
+    /// Parsing never fails: text that fits no structure is kept as flat
+    /// symbols, identifiers, and literals.
+    pub fn parse(aisp: &str) -> Self {
+        let mut nodes = Vec::new();
+        let mut rest = aisp;
+
+        while let Some(block) = find_block(rest) {
+            nodes.extend(parse_expression(&rest[..block.start]));
+            nodes.push(AispNode::Block {
+                name: block.name.to_string(),
+                delimiters: block.delimiters,
+                children: parse_expression(block.body),
+            });
+            rest = &rest[block.end..];
+        }
+        nodes.extend(parse_expression(rest));
+
+        Self { nodes }
+    }
+
+    /// Top-level blocks, in document order
+    pub fn blocks(&self) -> impl Iterator<Item = &AispNode> {
+        self.nodes
+            .iter()
+            .filter(|node| matches!(node, AispNode::Block { .. }))
+    }
+}
+
+impl AispConverter {
+    /// Convert prose to AISP and parse the result into a tree
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, AispNode};
+    ///
+    /// let doc = AispConverter::to_ast("for all x in S, x is greater than 0");
+    /// assert_eq!(doc.to_string(), AispConverter::convert("for all x in S, x is greater than 0", None).output);
+    ///
+    /// let doc = AispConverter::to_ast("y is at least 5");
+    /// assert!(matches!(&doc.nodes[0], AispNode::BinaryOp { op, .. } if op == "≥"));
+    /// ```
+    pub fn to_ast(prose: &str) -> AispDocument {
+        Self::to_ast_with_options(prose, None)
+    }
+
+    /// Convert prose to AISP with options and parse the result into a tree
+    pub fn to_ast_with_options(prose: &str, options: Option<ConversionOptions>) -> AispDocument {
+        AispDocument::parse(&Self::convert(prose, options).output)
+    }
+}
+
+/// A block located in source text
+struct BlockSpan<'a> {
+    /// Byte offset of `⟦`
+    start: usize,
+    /// Byte offset just past the closing delimiter
+    end: usize,
+    name: &'a str,
+    delimiters: (char, char),
+    body: &'a str,
+}
+
+/// Locate the first `⟦name⟧{...}` or `⟦name⟧⟨...⟩` block
+fn find_block(text: &str) -> Option<BlockSpan<'_>> {
+    lazy_static! {
+        /// A block header: `⟦name⟧` followed by its opening delimiter
+        static ref HEADER: Regex = Regex::new(r"⟦([^⟦⟧]*)⟧([{⟨])").unwrap();
+    }
+
+    for caps in HEADER.captures_iter(text) {
+        let whole = caps.get(0).unwrap();
+        let open = caps[2].chars().next().unwrap();
+        let close = if open == '{' { '}' } else { '⟩' };
+
+        let mut depth = 1;
+        for (offset, c) in text[whole.end()..].char_indices() {
+            if c == open {
+                depth += 1;
+            } else if c == close {
+                depth -= 1;
+                if depth == 0 {
+                    let body_end = whole.end() + offset;
+                    return Some(BlockSpan {
+                        start: whole.start(),
+                        end: body_end + close.len_utf8(),
+                        name: caps.get(1).unwrap().as_str(),
+                        delimiters: (open, close),
+                        body: &text[whole.end()..body_end],
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Split text into flat symbol, identifier, and literal tokens
+fn tokenize(text: &str) -> Vec<AispNode> {
+    lazy_static! {
+        /// A number at the start of the text, with an optional fraction and percent sign
+        static ref NUMBER: Regex = Regex::new(r"^\d+(?:\.\d+)?%?").unwrap();
+    }
+    let starts_symbol = |rest: &str| {
+        TOKEN_SYMBOLS
+            .iter()
+            .find(|symbol| rest.starts_with(**symbol))
+            .copied()
+    };
+
+    let mut tokens = Vec::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let (token, len) = if c.is_whitespace() {
+            let len = rest
+                .find(|c: char| !c.is_whitespace())
+                .unwrap_or(rest.len());
+            (AispNode::Literal(rest[..len].to_string()), len)
+        } else if c == '"' {
+            let len = rest[1..].find('"').map_or(rest.len(), |i| i + 2);
+            (AispNode::Literal(rest[..len].to_string()), len)
+        } else if let Some(m) = NUMBER.find(rest) {
+            (AispNode::Literal(m.as_str().to_string()), m.end())
+        } else if let Some(symbol) = starts_symbol(rest) {
+            (AispNode::Symbol(symbol.to_string()), symbol.len())
+        } else if c.is_alphabetic() || c == '_' {
+            let len = rest
+                .char_indices()
+                .find(|&(i, c)| {
+                    !(c.is_alphanumeric() || c == '_')
+                        || (!c.is_ascii() && starts_symbol(&rest[i..]).is_some())
+                })
+                .map_or(rest.len(), |(i, _)| i);
+            (AispNode::Identifier(rest[..len].to_string()), len)
+        } else if c.is_ascii() {
+            (AispNode::Literal(c.to_string()), 1)
+        } else {
+            (AispNode::Symbol(c.to_string()), c.len_utf8())
+        };
+        tokens.push(token);
+        rest = &rest[len..];
+    }
+    tokens
+}
+
+/// Parse text into quantified statements and operator trees
+fn parse_expression(text: &str) -> Vec<AispNode> {
+    let mut nodes = Vec::new();
+    let mut run = Vec::new();
+
+    // Structure never spans whitespace
+    for token in tokenize(text) {
+        if matches!(&token, AispNode::Literal(s) if s.starts_with(char::is_whitespace)) {
+            nodes.extend(parse_run(std::mem::take(&mut run)));
+            nodes.push(token);
+        } else {
+            run.push(token);
+        }
+    }
+    nodes.extend(parse_run(run));
+    nodes
+}
+
+/// Parse a whitespace-free token run
+fn parse_run(run: Vec<AispNode>) -> Vec<AispNode> {
+    let is_literal =
+        |node: &AispNode, text: &str| matches!(node, AispNode::Literal(s) if s == text);
+
+    let quantified = run.iter().enumerate().find_map(|(q, node)| match node {
+        AispNode::Symbol(s) if QUANTIFIERS.contains(&s.as_str()) => run[q + 1..]
+            .iter()
+            .position(|node| is_literal(node, ":"))
+            .filter(|&offset| offset > 0)
+            .map(|offset| (q, q + 1 + offset)),
+        _ => None,
+    });
+    let Some((q, colon)) = quantified else {
+        return parse_chains(run);
+    };
+
+    // The body runs to the end of the statement
+    let end = run[colon + 1..]
+        .iter()
+        .position(|node| is_literal(node, ";"))
+        .map_or(run.len(), |offset| colon + 1 + offset);
+
+    let mut tokens = run.into_iter();
+    let before: Vec<_> = tokens.by_ref().take(q).collect();
+    let quantifier = tokens.next().unwrap().to_string();
+    let binding: Vec<_> = tokens.by_ref().take(colon - q - 1).collect();
+    tokens.next();
+    let body: Vec<_> = tokens.by_ref().take(end - colon - 1).collect();
+
+    let mut nodes = parse_chains(before);
+    nodes.push(AispNode::Quantified {
+        quantifier,
+        binding: parse_chains(binding),
+        body: parse_run(body),
+    });
+    nodes.extend(parse_run(tokens.collect()));
+    nodes
+}
+
+/// Operator strength for an infix symbol token
+fn infix_precedence(node: &AispNode) -> Option<u8> {
+    match node {
+        AispNode::Symbol(s) => INFIX_OPERATORS
+            .iter()
+            .find(|(op, _)| op == s)
+            .map(|(_, precedence)| *precedence),
+        _ => None,
+    }
+}
+
+/// Whether a token can stand on either side of an infix operator
+fn is_operand(node: &AispNode) -> bool {
+    match node {
+        AispNode::Identifier(_) => true,
+        AispNode::Symbol(s) => {
+            infix_precedence(node).is_none() && !QUANTIFIERS.contains(&s.as_str()) && s != "¬"
+        }
+        AispNode::Literal(s) => s.starts_with(|c: char| c.is_ascii_digit() || c == '"'),
+        _ => false,
+    }
+}
+
+/// Fold `operand op operand (op operand)*` sequences into operator trees
+fn parse_chains(tokens: Vec<AispNode>) -> Vec<AispNode> {
+    let mut nodes = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        let mut j = i + 1;
+        if is_operand(&tokens[i]) {
+            while j + 1 < tokens.len()
+                && infix_precedence(&tokens[j]).is_some()
+                && is_operand(&tokens[j + 1])
+            {
+                j += 2;
+            }
+        }
+        nodes.push(build_tree(&tokens[i..j]));
+        i = j;
+    }
+    nodes
+}
+
+/// Build a tree from an alternating operand/operator slice
+///
+/// Splits at the rightmost weakest operator, so equal operators associate left.
+fn build_tree(chain: &[AispNode]) -> AispNode {
+    if chain.len() == 1 {
+        return chain[0].clone();
+    }
+    let split = (1..chain.len())
+        .step_by(2)
+        .min_by_key(|&i| (infix_precedence(&chain[i]), std::cmp::Reverse(i)))
+        .unwrap();
+    AispNode::BinaryOp {
+        op: chain[split].to_string(),
+        left: Box::new(build_tree(&chain[..split])),
+        right: Box::new(build_tree(&chain[split + 1..])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::ConversionTier;
+
+    #[test]
+    fn test_display_renders_converted_output() {
+        let prose = "for all x in S, x is greater than 0. Define y as 5.";
+        for tier in [
+            ConversionTier::Minimal,
+            ConversionTier::Standard,
+            ConversionTier::Full,
+        ] {
+            let options = ConversionOptions {
+                tier: Some(tier),
+                ..Default::default()
+            };
+            let output = AispConverter::convert(prose, Some(options.clone())).output;
+            let doc = AispConverter::to_ast_with_options(prose, Some(options));
+            assert_eq!(doc.to_string(), output);
+        }
+    }
+
+    #[test]
+    fn test_parse_blocks() {
+        let doc = AispDocument::parse("𝔸1.0.x@2026-01-01\n\n⟦Λ:Funcs⟧{\n  y≜5\n}\n\n⟦Ε⟧⟨δ≜0.82⟩");
+        let names: Vec<_> = doc
+            .blocks()
+            .map(|block| match block {
+                AispNode::Block { name, .. } => name.as_str(),
+                _ => unreachable!(),
+            })
+            .collect();
+        assert_eq!(names, ["Λ:Funcs", "Ε"]);
+
+        let AispNode::Block { children, .. } = doc.blocks().next().unwrap() else {
+            unreachable!()
+        };
+        assert!(children.iter().any(|node| matches!(
+            node,
+            AispNode::BinaryOp { op, .. } if op == "≜"
+        )));
+    }
+
+    #[test]
+    fn test_parse_quantified_precedence() {
+        let doc = AispDocument::parse("∀x∈S:x>0∧x<10");
+        let [AispNode::Quantified {
+            quantifier,
+            binding,
+            body,
+        }] = doc.nodes.as_slice()
+        else {
+            panic!("{:?}", doc.nodes)
+        };
+        assert_eq!(quantifier, "∀");
+        assert!(matches!(&binding[..], [AispNode::BinaryOp { op, .. }] if op == "∈"));
+
+        // ∧ binds looser than the comparisons on either side
+        let [AispNode::BinaryOp { op, left, right }] = body.as_slice() else {
+            panic!("{:?}", body)
+        };
+        assert_eq!(op, "∧");
+        assert_eq!(left.to_string(), "x>0");
+        assert_eq!(right.to_string(), "x<10");
+    }
+
+    #[test]
+    fn test_parse_keeps_unstructured_text_flat() {
+        let doc = AispDocument::parse("∀ x∈S, widgets \"glow\"");
+        assert_eq!(doc.to_string(), "∀ x∈S, widgets \"glow\"");
+        assert_eq!(doc.nodes[0], AispNode::Symbol("∀".to_string()));
+        assert!(doc
+            .nodes
+            .contains(&AispNode::Identifier("widgets".to_string())));
+        assert!(doc
+            .nodes
+            .contains(&AispNode::Literal("\"glow\"".to_string())));
+    }
+}
@@ -0,0 +1,199 @@
+//! LRU-cached conversion
+//!
+//! Keeps recent `ConversionResult`s keyed on the prose and tier, for
+//! servers that convert the same fragments repeatedly.
+
+use crate::converter::{AispConverter, ConversionOptions, ConversionResult, ConversionTier};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+
+/// Cache key: (prose, resolved tier)
+type CacheKey = (String, ConversionTier);
+
+/// Converter instance with an LRU result cache
+///
+/// `CachedConverter` is `Send + Sync`: the cache sits behind a `Mutex`, so one
+/// instance can be shared across threads (e.g. in an `Arc`). The lock is only
+/// held for lookups and inserts, never while converting.
+///
+/// Only conversions whose options set nothing besides `tier` are cached; any
+/// other option bypasses the cache. Cached Standard/Full results keep the
+/// header date from when they were first converted.
+pub struct CachedConverter {
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    /// Cached results with the tick of their last use
+    entries: HashMap<CacheKey, (ConversionResult, u64)>,
+    /// Keys by the tick of their last use, least recently used first
+    recency: BTreeMap<u64, CacheKey>,
+    tick: u64,
+}
+
+impl AispConverter {
+    /// Create a converter instance that caches up to `capacity` results
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// let converter = AispConverter::with_cache(128);
+    /// let first = converter.convert("for all x in S", None);
+    /// let second = converter.convert("for all x in S", None);
+    /// assert_eq!(first.output, second.output);
+    /// assert_eq!(converter.len(), 1);
+    /// ```
+    pub fn with_cache(capacity: usize) -> CachedConverter {
+        CachedConverter {
+            capacity,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+}
+
+impl CachedConverter {
+    /// Convert prose to AISP, reusing a cached result when available
+    pub fn convert(&self, prose: &str, options: Option<ConversionOptions>) -> ConversionResult {
+        let opts = options.unwrap_or_default();
+        let cacheable = self.capacity > 0
+            && ConversionOptions {
+                tier: None,
+                ..opts.clone()
+            } == ConversionOptions::default();
+        if !cacheable {
+            return AispConverter::convert(prose, Some(opts));
+        }
+
+        let tier = opts
+            .tier
+            .unwrap_or_else(|| AispConverter::detect_tier(prose));
+        let key = (prose.to_string(), tier);
+
+        if let Some(result) = self.lookup(&key) {
+            return result;
+        }
+
+        let result = AispConverter::convert(
+            prose,
+            Some(ConversionOptions {
+                tier: Some(tier),
+                ..opts
+            }),
+        );
+        self.insert(key, result.clone());
+        result
+    }
+
+    /// Maximum number of cached results
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop all cached results
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.recency.clear();
+    }
+
+    fn lookup(&self, key: &CacheKey) -> Option<ConversionResult> {
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+
+        let CacheState {
+            entries, recency, ..
+        } = &mut *state;
+        let (result, last_used) = entries.get_mut(key)?;
+        recency.remove(last_used);
+        recency.insert(tick, key.clone());
+        *last_used = tick;
+        Some(result.clone())
+    }
+
+    fn insert(&self, key: CacheKey, result: ConversionResult) {
+        let mut state = self.state.lock().unwrap();
+        state.tick += 1;
+        let tick = state.tick;
+
+        // Evict the least recently used entry when full
+        if state.entries.len() >= self.capacity && !state.entries.contains_key(&key) {
+            if let Some((_, oldest)) = state.recency.pop_first() {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        if let Some((_, last_used)) = state.entries.insert(key.clone(), (result, tick)) {
+            state.recency.remove(&last_used);
+        }
+        state.recency.insert(tick, key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let converter = AispConverter::with_cache(2);
+        converter.convert("for all x in S", None);
+        converter.convert("x and y", None);
+        // Touch the first entry so the second becomes least recently used
+        converter.convert("for all x in S", None);
+        converter.convert("x or y", None);
+
+        assert_eq!(converter.len(), 2);
+        let state = converter.state.lock().unwrap();
+        assert!(!state
+            .entries
+            .contains_key(&("x and y".to_string(), ConversionTier::Minimal)));
+        assert_eq!(state.recency.len(), state.entries.len());
+        let order: Vec<_> = state
+            .recency
+            .values()
+            .map(|(prose, _)| prose.as_str())
+            .collect();
+        assert_eq!(order, ["for all x in S", "x or y"]);
+    }
+
+    #[test]
+    fn test_cache_keys_on_tier_and_bypasses_other_options() {
+        let converter = AispConverter::with_cache(8);
+        let minimal = converter.convert("Define x as 5", None);
+        let full = converter.convert(
+            "Define x as 5",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Full),
+                ..Default::default()
+            }),
+        );
+        assert_ne!(minimal.output, full.output);
+        assert_eq!(converter.len(), 2);
+
+        converter.convert(
+            "Define x as 5",
+            Some(ConversionOptions {
+                annotate_source: true,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(converter.len(), 2);
+
+        converter.clear();
+        assert!(converter.is_empty());
+    }
+}
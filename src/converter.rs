@@ -5,13 +5,15 @@
 //! - Standard: + Header + evidence block (1.5-2x tokens)
 //! - Full: + All blocks + proofs (4-8x tokens)
 
-use crate::rosetta::RosettaStone;
-use chrono::Utc;
+use crate::engine::Substitution;
+use crate::rosetta::{ConversionMode, PlannedSubstitution, RosettaOptions, RosettaStone, ROSETTA};
+use chrono::{DateTime, Utc};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-/// Conversion tier
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Conversion tier (ordered Minimal < Standard < Full)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ConversionTier {
     Minimal,
@@ -30,20 +32,513 @@ impl std::fmt::Display for ConversionTier {
 }
 
 /// Conversion options
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct ConversionOptions {
     /// Force specific tier (auto-detect if None)
     pub tier: Option<ConversionTier>,
-    /// Confidence threshold (default: 0.8)
+    /// Minimum acceptable confidence (default: 0.8)
+    ///
+    /// Results below it are flagged with `below_threshold`, rejected by
+    /// `try_convert`, and left unconverted with `passthrough_below_threshold`.
+    /// Standard and Full inherit the confidence of their minimal
+    /// pass, so the outcome is the same at every tier.
     pub confidence_threshold: Option<f64>,
+    /// Pin the document header date for reproducible output (current time if None)
+    pub date: Option<DateTime<Utc>>,
+    /// Keep each source sentence inline as a `⟨prose:"..."⟩` annotation
+    pub annotate_source: bool,
+    /// Match plural nouns against singular patterns ("booleans" → "boolean")
+    pub singularize: bool,
+    /// Split multi-word patterns written without spaces ("greaterthan" → "greater than")
+    pub split_concatenated: bool,
+    /// Write spelled-out numbers as digits ("three users" → "3 users", "first" → "1st")
+    /// See `RosettaStone::normalize_numerals`
+    pub normalize_numerals: bool,
+    /// Join multi-word comparison subjects into snake_case identifiers
+    /// ("response time must be under 200ms" → `response_time<200ms`)
+    pub normalize_identifiers: bool,
+    /// Block layout and evidence values for Full-tier documents
+    pub full_template: FullTemplate,
+    /// Convert each sentence as its own clause, joined by this separator (e.g. "\n" or "∧")
+    pub clause_separator: Option<String>,
+    /// Convert each line on its own and join the results with newlines
+    ///
+    /// No pattern, phrase, or operator cleanup spans two lines, so each item of
+    /// a bulleted requirements list stays one requirement. Leading indentation
+    /// is kept; `clause_separator` applies within each line.
+    pub line_as_clause: bool,
+    /// Leave identifiers (camelCase names, words touching an operator) out of `unmapped`
+    pub exclude_identifiers: bool,
+    /// Skip Standard/Full blocks whose only content is `∅`
+    ///
+    /// The evidence block `⟦Ε⟧` is always kept. A Full document that loses
+    /// its Errors block this way classifies as Standard.
+    pub omit_empty_blocks: bool,
+    /// Thresholds and trigger words for tier auto-detection (default policy if None)
+    pub tier_policy: Option<TierPolicy>,
+    /// Which table substitutions to apply; `Lossless` keeps only exactly reversible ones
+    pub mode: ConversionMode,
+    /// Skip folding smart quotes, dashes, and special spaces to ASCII before matching
+    pub preserve_typography: bool,
+    /// Return the prose unchanged, flagged `passthrough`, when confidence is below the threshold
+    pub passthrough_below_threshold: bool,
+    /// Treat AISP symbols already in the prose as converted: leave them as
+    /// written and convert only the prose around them
+    ///
+    /// Half-converted input ("∀ users must authenticate") is then converted
+    /// even when it is symbol-heavy enough to pass `RosettaStone::is_aisp`;
+    /// only whole documents are returned as-is.
+    pub preserve_symbols: bool,
+    /// Convert adjectival comparisons into attribute comparisons
+    /// (see `RosettaOptions::symbolize_comparatives`)
+    pub symbolize_comparatives: bool,
+}
+
+impl ConversionOptions {
+    /// Confidence threshold used when `confidence_threshold` is None
+    pub const DEFAULT_CONFIDENCE_THRESHOLD: f64 = 0.8;
+
+    /// The configured confidence threshold, or the default
+    pub fn threshold(&self) -> f64 {
+        self.confidence_threshold
+            .unwrap_or(Self::DEFAULT_CONFIDENCE_THRESHOLD)
+    }
+}
+
+/// Thresholds and trigger words used to auto-detect a conversion tier
+///
+/// Trigger words match whole words, case-insensitively. Proof, contractor, or
+/// intent words (or type and rule words together) select Full; any type, rule,
+/// complex-logic, or API word, or more than `standard_word_count` words,
+/// selects Standard.
+///
+/// # Example
+/// ```
+/// use rosetta_aisp::{AispConverter, ConversionTier, TierPolicy};
+///
+/// let mut policy = TierPolicy {
+///     standard_word_count: 50,
+///     ..Default::default()
+/// };
+/// policy.proof_words.push("lemma".to_string());
+///
+/// assert_eq!(
+///     AispConverter::detect_tier_with_policy("State the lemma", policy),
+///     ConversionTier::Full
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TierPolicy {
+    /// Word count above which prose is at least Standard (default: 20)
+    pub standard_word_count: usize,
+    /// Type vocabulary ("type", "struct", "schema", ...)
+    pub type_words: Vec<String>,
+    /// Rule vocabulary ("must", "always", "ensure", ...)
+    pub rule_words: Vec<String>,
+    /// Proof vocabulary ("prove", "verify", "qed", ...)
+    pub proof_words: Vec<String>,
+    /// Complex-logic phrases ("for all", "implies", ...)
+    pub complex_words: Vec<String>,
+    /// API vocabulary ("endpoint", "handler", ...)
+    pub api_words: Vec<String>,
+    /// Contract vocabulary ("invariant", "precondition", ...)
+    pub contractor_words: Vec<String>,
+    /// Intent vocabulary ("goal", "purpose", ...)
+    pub intent_words: Vec<String>,
+}
+
+impl Default for TierPolicy {
+    fn default() -> Self {
+        let words = |list: &[&str]| list.iter().map(|w| w.to_string()).collect();
+        Self {
+            standard_word_count: 20,
+            type_words: words(&[
+                "type",
+                "class",
+                "struct",
+                "interface",
+                "schema",
+                "model",
+                "entity",
+            ]),
+            rule_words: words(&[
+                "must",
+                "should",
+                "always",
+                "never",
+                "require",
+                "ensure",
+                "guarantee",
+                "constraint",
+                "rule",
+            ]),
+            proof_words: words(&[
+                "prove",
+                "verify",
+                "validate",
+                "certify",
+                "demonstrate",
+                "qed",
+                "proven",
+            ]),
+            complex_words: words(&[
+                "for all",
+                "there exists",
+                "if and only if",
+                "implies",
+                "therefore",
+            ]),
+            api_words: words(&[
+                "api",
+                "endpoint",
+                "route",
+                "controller",
+                "handler",
+                "service",
+            ]),
+            contractor_words: words(&[
+                "delta",
+                "invariant",
+                "precondition",
+                "postcondition",
+                "requires",
+                "ensures",
+            ]),
+            intent_words: words(&[
+                "intent",
+                "goal",
+                "purpose",
+                "objective",
+                "fitness",
+                "risk",
+                "utility",
+            ]),
+        }
+    }
+}
+
+impl TierPolicy {
+    /// Whether any of `words` occurs in `prose` as a whole word
+    fn mentions(prose: &str, words: &[String]) -> bool {
+        Self::first_mention(prose, words).is_some()
+    }
+
+    /// The first of `words` to occur in `prose` as a whole word, as written in the prose
+    fn first_mention(prose: &str, words: &[String]) -> Option<String> {
+        if words.is_empty() {
+            return None;
+        }
+        let alternation: Vec<_> = words.iter().map(|w| regex::escape(w)).collect();
+        Regex::new(&format!(r"(?i)\b(?:{})\b", alternation.join("|")))
+            .unwrap()
+            .find(prose)
+            .map(|m| m.as_str().to_string())
+    }
+}
+
+/// A block in a Full-tier document
+#[derive(Debug, Clone, PartialEq)]
+pub enum FullBlock {
+    /// `⟦Ω:Meta⟧` domain and version
+    Meta,
+    /// `⟦Σ:Types⟧` inferred types
+    Types,
+    /// `⟦Γ:Rules⟧` inferred rules
+    Rules,
+    /// `⟦Λ:Funcs⟧` the converted prose
+    Funcs,
+    /// `⟦Χ:Errors⟧` inferred error cases
+    Errors,
+    /// A house-style block such as `⟦Ψ:Intents⟧{...}`
+    Custom { header: String, body: String },
+}
+
+/// Full-tier document layout: block order and evidence values
+///
+/// The default is the standard layout (Meta, Types, Rules, Funcs, Errors)
+/// with `δ≜0.82;φ≜100;τ≜◊⁺⁺`. Documents that drop Types, Rules, or Errors
+/// classify as Standard.
+///
+/// # Example
+/// ```
+/// use rosetta_aisp::{FullBlock, FullTemplate};
+///
+/// let template = FullTemplate::default()
+///     .without_block(FullBlock::Errors)
+///     .with_block(FullBlock::Custom {
+///         header: "Ψ:Intents".to_string(),
+///         body: "  goal≜ship".to_string(),
+///     })
+///     .delta(0.9)
+///     .tau("◊⁺");
+/// assert_eq!(template.blocks.len(), 5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FullTemplate {
+    /// Blocks in output order; the evidence block always comes last
+    pub blocks: Vec<FullBlock>,
+    /// Evidence density `δ`
+    pub delta: f64,
+    /// Evidence coverage `φ`
+    pub phi: u32,
+    /// Evidence quality tier `τ`
+    pub tau: String,
+}
+
+impl Default for FullTemplate {
+    fn default() -> Self {
+        FullTemplate {
+            blocks: vec![
+                FullBlock::Meta,
+                FullBlock::Types,
+                FullBlock::Rules,
+                FullBlock::Funcs,
+                FullBlock::Errors,
+            ],
+            delta: 0.82,
+            phi: 100,
+            tau: "◊⁺⁺".to_string(),
+        }
+    }
+}
+
+impl FullTemplate {
+    /// Replace the block order
+    pub fn blocks(mut self, blocks: Vec<FullBlock>) -> Self {
+        self.blocks = blocks;
+        self
+    }
+
+    /// Append a block
+    pub fn with_block(mut self, block: FullBlock) -> Self {
+        self.blocks.push(block);
+        self
+    }
+
+    /// Remove every occurrence of a block
+    pub fn without_block(mut self, block: FullBlock) -> Self {
+        self.blocks.retain(|b| *b != block);
+        self
+    }
+
+    /// Set evidence density `δ`
+    pub fn delta(mut self, delta: f64) -> Self {
+        self.delta = delta;
+        self
+    }
+
+    /// Set evidence coverage `φ`
+    pub fn phi(mut self, phi: u32) -> Self {
+        self.phi = phi;
+        self
+    }
+
+    /// Set evidence quality tier `τ`
+    pub fn tau(mut self, tau: &str) -> Self {
+        self.tau = tau.to_string();
+        self
+    }
+}
+
+/// Conversion failure
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConversionError {
+    /// Confidence fell below the configured threshold
+    BelowThreshold { confidence: f64, threshold: f64 },
+    /// The generated document failed `aisp::validate`
+    Invalid {
+        /// Header of the first block that fails on its own (e.g. "⟦Σ:Types⟧"), if any
+        block: Option<String>,
+        /// The validator's error message
+        details: String,
+    },
+    /// No conversion profile is registered under this name
+    UnknownProfile { name: String },
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::BelowThreshold {
+                confidence,
+                threshold,
+            } => write!(
+                f,
+                "confidence {:.2} is below threshold {:.2}",
+                confidence, threshold
+            ),
+            ConversionError::Invalid {
+                block: Some(block),
+                details,
+            } => write!(f, "output failed AISP validation in {}: {}", block, details),
+            ConversionError::Invalid {
+                block: None,
+                details,
+            } => write!(f, "output failed AISP validation: {}", details),
+            ConversionError::UnknownProfile { name } => {
+                write!(f, "unknown conversion profile \"{}\"", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<ValidationError> for ConversionError {
+    fn from(err: ValidationError) -> Self {
+        match err {
+            ValidationError::Invalid { block, report } => ConversionError::Invalid {
+                block,
+                details: ValidationError::message(&report).to_string(),
+            },
+        }
+    }
+}
+
+/// Converted output that failed `aisp::validate`
+#[derive(Debug, Clone)]
+pub enum ValidationError {
+    /// The validator rejected the output
+    Invalid {
+        /// Header of the first block that fails on its own (e.g. "⟦Σ:Types⟧"), if any
+        block: Option<String>,
+        /// The validator's result: its error, tier, density, and block metrics
+        report: aisp::ValidationResult,
+    },
+}
+
+impl ValidationError {
+    /// The validator's error message, or a generic one when it gave none
+    fn message(report: &aisp::ValidationResult) -> &'static str {
+        report.error.unwrap_or("invalid AISP document")
+    }
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationError::Invalid {
+                block: Some(block),
+                report,
+            } => write!(
+                f,
+                "output failed AISP validation in {}: {}",
+                block,
+                Self::message(report)
+            ),
+            ValidationError::Invalid {
+                block: None,
+                report,
+            } => write!(f, "output failed AISP validation: {}", Self::message(report)),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Size of a piece of text
+///
+/// Symbols are multi-byte (`∀` is 3 bytes), so `chars` and `graphemes`
+/// reflect visual size better than `bytes`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TextSize {
+    /// UTF-8 bytes
+    pub bytes: usize,
+    /// Unicode scalar values
+    pub chars: usize,
+    /// Extended grapheme clusters; None without the `graphemes` feature
+    pub graphemes: Option<usize>,
+}
+
+impl TextSize {
+    /// Measure a piece of text
+    pub fn of(text: &str) -> Self {
+        #[cfg(feature = "graphemes")]
+        let graphemes =
+            Some(unicode_segmentation::UnicodeSegmentation::graphemes(text, true).count());
+        #[cfg(not(feature = "graphemes"))]
+        let graphemes = None;
+
+        TextSize {
+            bytes: text.len(),
+            chars: text.chars().count(),
+            graphemes,
+        }
+    }
+}
+
+impl std::ops::Add for TextSize {
+    type Output = TextSize;
+
+    fn add(self, other: TextSize) -> TextSize {
+        TextSize {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            graphemes: self.graphemes.zip(other.graphemes).map(|(a, b)| a + b),
+        }
+    }
 }
 
 /// Token statistics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// `input`, `output`, and the ratios are measured in bytes of UTF-8 text;
+/// `input_size` and `output_size` add char and grapheme counts.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TokenStats {
+    /// Input bytes
     pub input: usize,
+    /// Output bytes
     pub output: usize,
+    /// `output / input`, rounded to 2 decimals (0.55 means the output is 55% of
+    /// the input's size); 0.0 for empty input
     pub ratio: f64,
+    /// `input / output`, rounded to 2 decimals (2.0 means 2x smaller); 0.0 for empty output
+    #[serde(default)]
+    pub compression_ratio: f64,
+    /// Share of the input size saved, in percent rounded to 1 decimal
+    /// Negative when the output is larger (Standard/Full headers); 0.0 for empty input
+    #[serde(default)]
+    pub savings_percent: f64,
+    /// Input size in bytes, chars, and graphemes
+    #[serde(default)]
+    pub input_size: TextSize,
+    /// Output size in bytes, chars, and graphemes
+    #[serde(default)]
+    pub output_size: TextSize,
+}
+
+impl TokenStats {
+    /// Measure input/output sizes and their ratio (rounded to 2 decimals)
+    pub(crate) fn measure(input: &str, output: &str) -> Self {
+        Self::from_sizes(TextSize::of(input), TextSize::of(output))
+    }
+
+    /// Build stats from input/output sizes
+    pub(crate) fn from_sizes(input_size: TextSize, output_size: TextSize) -> Self {
+        let (input, output) = (input_size.bytes, output_size.bytes);
+        let (input_f, output_f) = (input as f64, output as f64);
+        TokenStats {
+            input,
+            output,
+            ratio: if input == 0 {
+                0.0
+            } else {
+                (output_f / input_f * 100.0).round() / 100.0
+            },
+            compression_ratio: if output == 0 {
+                0.0
+            } else {
+                (input_f / output_f * 100.0).round() / 100.0
+            },
+            savings_percent: if input == 0 {
+                0.0
+            } else {
+                ((1.0 - output_f / input_f) * 1000.0).round() / 10.0
+            },
+            input_size,
+            output_size,
+        }
+    }
 }
 
 /// Conversion result
@@ -53,8 +548,30 @@ pub struct ConversionResult {
     pub output: String,
     /// Confidence score (0.0 - 1.0)
     pub confidence: f64,
+    /// Each symbol category's contribution to `confidence`
+    #[serde(default)]
+    pub category_confidence: HashMap<String, f64>,
+    /// Confidence with matches weighted by pattern specificity (0.0 - 1.0)
+    ///
+    /// A long phrase such as "if and only if" counts for more than a short
+    /// common word such as "in"; see `RosettaStone::pattern_specificity`.
+    #[serde(default)]
+    pub confidence_weighted: f64,
     /// Words that couldn't be mapped
     pub unmapped: Vec<String>,
+    /// Unmapped words that look like typos of known patterns, as (word, pattern)
+    /// Only populated with the `fuzzy` feature
+    #[serde(default)]
+    pub fuzzy_candidates: Vec<(String, String)>,
+    /// Substitutions left out by `ConversionMode::Lossless`, as (phrase, symbol)
+    #[serde(default)]
+    pub skipped_substitutions: Vec<(String, String)>,
+    /// How many times each Rosetta pattern matched (inline hints count under their symbol)
+    #[serde(default)]
+    pub pattern_counts: HashMap<String, usize>,
+    /// Problems with the input that conversion worked around, such as unbalanced parentheses
+    #[serde(default)]
+    pub warnings: Vec<String>,
     /// Conversion tier used
     pub tier: ConversionTier,
     /// Token statistics
@@ -62,8 +579,159 @@ pub struct ConversionResult {
     /// Whether LLM fallback was used (for gear-core integration)
     #[serde(default)]
     pub used_fallback: bool,
+    /// Whether `confidence` is below the requested `confidence_threshold`
+    #[serde(default)]
+    pub below_threshold: bool,
+    /// Whether `output` is the input prose, returned unconverted because confidence
+    /// was below the threshold (see `ConversionOptions::passthrough_below_threshold`)
+    #[serde(default)]
+    pub passthrough: bool,
+    /// Share of input tokens that were already AISP symbols (see `RosettaStone::symbol_share`)
+    #[serde(default)]
+    pub symbol_share: f64,
+}
+
+/// Result of `AispConverter::convert_budgeted`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BudgetedConversion {
+    /// Prose with the chosen substitutions applied
+    pub output: String,
+    /// Substitutions applied, in input order, with spans into the original prose
+    pub applied: Vec<PlannedSubstitution>,
+    /// Tokens in `output`, as counted by `RosettaStone::tokenize`
+    pub tokens: usize,
+}
+
+/// How far a conversion can be trusted, from `AispConverter::quality_score`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QualityScore {
+    /// Share of the prose mapped to symbols (`ConversionResult::confidence`)
+    pub forward_confidence: f64,
+    /// Similarity of the prose to its round trip through AISP
+    /// (`RosettaStone::semantic_similarity`)
+    pub roundtrip_fidelity: f64,
+    /// `CONFIDENCE_WEIGHT * forward_confidence + FIDELITY_WEIGHT * roundtrip_fidelity`
+    pub combined: f64,
+}
+
+impl QualityScore {
+    /// Weight of `forward_confidence` in `combined`
+    pub const CONFIDENCE_WEIGHT: f64 = 0.5;
+    /// Weight of `roundtrip_fidelity` in `combined`
+    pub const FIDELITY_WEIGHT: f64 = 0.5;
+}
+
+impl ConversionResult {
+    /// Merge separately converted parts into one result
+    ///
+    /// Outputs are joined by blank lines, token stats are summed, unmapped words
+    /// are merged and deduplicated, confidences are the minimum across parts, and
+    /// the tier is the highest used by any part.
+    pub fn merge(results: &[ConversionResult]) -> ConversionResult {
+        let input_tokens: usize = results.iter().map(|r| r.tokens.input).sum();
+        let input_size = results
+            .iter()
+            .map(|r| r.tokens.input_size)
+            .reduce(|a, b| a + b)
+            .unwrap_or_default();
+        let output = results
+            .iter()
+            .map(|r| r.output.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+
+        // Category contributions are weighted by each part's share of the input
+        let mut category_confidence: HashMap<String, f64> = HashMap::new();
+        for result in results {
+            let weight = if input_tokens == 0 {
+                0.0
+            } else {
+                result.tokens.input as f64 / input_tokens as f64
+            };
+            for (category, confidence) in &result.category_confidence {
+                *category_confidence.entry(category.clone()).or_insert(0.0) += confidence * weight;
+            }
+        }
+
+        let mut unmapped: Vec<String> = results.iter().flat_map(|r| r.unmapped.clone()).collect();
+        unmapped.sort();
+        unmapped.dedup();
+
+        let mut fuzzy_candidates: Vec<(String, String)> = results
+            .iter()
+            .flat_map(|r| r.fuzzy_candidates.clone())
+            .collect();
+        fuzzy_candidates.sort();
+        fuzzy_candidates.dedup();
+
+        let skipped_substitutions = results
+            .iter()
+            .flat_map(|r| r.skipped_substitutions.clone())
+            .collect();
+
+        let warnings = results.iter().flat_map(|r| r.warnings.clone()).collect();
+
+        let symbol_share = if input_tokens == 0 {
+            0.0
+        } else {
+            results
+                .iter()
+                .map(|r| r.symbol_share * r.tokens.input as f64)
+                .sum::<f64>()
+                / input_tokens as f64
+        };
+
+        let mut pattern_counts: HashMap<String, usize> = HashMap::new();
+        for (pattern, count) in results.iter().flat_map(|r| &r.pattern_counts) {
+            *pattern_counts.entry(pattern.clone()).or_insert(0) += count;
+        }
+
+        ConversionResult {
+            tokens: TokenStats::from_sizes(input_size, TextSize::of(&output)),
+            output,
+            confidence: results.iter().map(|r| r.confidence).fold(1.0, f64::min),
+            category_confidence,
+            confidence_weighted: results
+                .iter()
+                .map(|r| r.confidence_weighted)
+                .fold(1.0, f64::min),
+            unmapped,
+            fuzzy_candidates,
+            skipped_substitutions,
+            pattern_counts,
+            warnings,
+            tier: results
+                .iter()
+                .map(|r| r.tier)
+                .max()
+                .unwrap_or(ConversionTier::Minimal),
+            used_fallback: results.iter().any(|r| r.used_fallback),
+            below_threshold: results.iter().any(|r| r.below_threshold),
+            passthrough: results.iter().any(|r| r.passthrough),
+            symbol_share,
+        }
+    }
 }
 
+/// Rule inference table as (triggers, rule), highest priority first:
+/// requirements, then quantified rules, domain rules, and contractors
+const RULE_PRIORITY: &[(&[&str], &str)] = &[
+    (&["must", "require"], "  ∀x:T:require(x)⇒proceed(x)"),
+    (&["valid", "check"], "  ∀x:T:valid(x)⇒accept(x)"),
+    (&["all", "every"], "  ∀x∈S:P(x)"),
+    (&["unique"], "  ∃!x:T:unique(x)"),
+    (&["constant", "immutable"], "  ∀c∈Const:c.immutable≡⊤"),
+    (&["admin"], "  ∀u∈User:u.admin≡⊤⇒allow(u)"),
+    // Contractor detections
+    (&["invariant", "always true"], "  Inv(s)≜always(s)"),
+    (&["precondition", "before"], "  Pre(f)≜req(args)"),
+    (
+        &["postcondition", "after", "ensures"],
+        "  Post(f)≜guarantee(result)",
+    ),
+    (&["delta", "change"], "  Δ(s)≜s'−s"),
+];
+
 /// AISP Converter
 ///
 /// Provides deterministic prose ↔ AISP conversion using Rosetta Stone mappings.
@@ -72,6 +740,9 @@ pub struct AispConverter;
 impl AispConverter {
     /// Convert prose to AISP with specified options
     ///
+    /// Empty or whitespace-only input converts to an empty Minimal result with
+    /// confidence 1.0, whatever tier is requested.
+    ///
     /// # Example
     /// ```
     /// use rosetta_aisp::{AispConverter, ConversionOptions, ConversionTier};
@@ -88,208 +759,935 @@ impl AispConverter {
     /// ```
     pub fn convert(prose: &str, options: Option<ConversionOptions>) -> ConversionResult {
         let opts = options.unwrap_or_default();
-        let tier = opts.tier.unwrap_or_else(|| Self::detect_tier(prose));
+
+        // Nothing to convert; don't wrap an empty body in document scaffolding
+        if prose.trim().is_empty() {
+            return Self::already_converted(prose, ConversionTier::Minimal);
+        }
+
+        // Already-converted input is returned as-is unless a higher tier is requested
+        if Self::is_converted(prose, &opts) {
+            let current = Self::classify_tier(prose);
+            if opts.tier.is_none_or(|tier| tier <= current) {
+                return Self::already_converted(prose, current);
+            }
+        }
+
+        let tier = opts.tier.unwrap_or_else(|| {
+            Self::detect_tier_with_policy(prose, opts.tier_policy.clone().unwrap_or_default())
+        });
 
         let result = match tier {
-            ConversionTier::Minimal => Self::convert_minimal(prose),
-            ConversionTier::Standard => Self::convert_standard(prose),
-            ConversionTier::Full => Self::convert_full(prose),
+            ConversionTier::Minimal => Self::convert_minimal(prose, &opts),
+            ConversionTier::Standard => Self::convert_standard(prose, &opts),
+            ConversionTier::Full => Self::convert_full(prose, &opts),
         };
 
+        let below_threshold = result.confidence < opts.threshold();
+        if below_threshold && opts.passthrough_below_threshold {
+            return ConversionResult {
+                output: prose.to_string(),
+                tokens: TokenStats::measure(prose, prose),
+                below_threshold,
+                passthrough: true,
+                ..result
+            };
+        }
+
         ConversionResult {
-            tokens: TokenStats {
-                input: prose.len(),
-                output: result.output.len(),
-                ratio: if prose.is_empty() {
-                    0.0
-                } else {
-                    (result.output.len() as f64 / prose.len() as f64 * 100.0).round() / 100.0
-                },
-            },
+            tokens: TokenStats::measure(prose, &result.output),
+            below_threshold,
             ..result
         }
     }
 
-    /// Auto-detect appropriate tier based on prose complexity
+    /// Convert prose to AISP, or return it unchanged when confidence is below `min_confidence`
+    ///
+    /// A passed-through result has `passthrough` set and the prose as its
+    /// `output`; its confidence and unmapped words still describe the attempt.
     ///
     /// # Example
     /// ```
-    /// use rosetta_aisp::{AispConverter, ConversionTier};
+    /// use rosetta_aisp::AispConverter;
     ///
-    /// assert_eq!(AispConverter::detect_tier("Define x as 5"), ConversionTier::Minimal);
-    /// assert_eq!(
-    ///     AispConverter::detect_tier("The user must authenticate to access the API"),
-    ///     ConversionTier::Standard
-    /// );
+    /// let result = AispConverter::convert_or_passthrough("Lorem ipsum and dolor", 0.8);
+    /// assert!(result.passthrough);
+    /// assert_eq!(result.output, "Lorem ipsum and dolor");
+    ///
+    /// let result = AispConverter::convert_or_passthrough("∀x∈S", 0.8);
+    /// assert!(!result.passthrough);
     /// ```
-    pub fn detect_tier(prose: &str) -> ConversionTier {
-        let word_count = prose.split_whitespace().count();
-
-        let types_regex =
-            Regex::new(r"(?i)\b(type|class|struct|interface|schema|model|entity)\b").unwrap();
-        let rules_regex = Regex::new(
-            r"(?i)\b(must|should|always|never|require|ensure|guarantee|constraint|rule)\b",
+    pub fn convert_or_passthrough(prose: &str, min_confidence: f64) -> ConversionResult {
+        Self::convert(
+            prose,
+            Some(ConversionOptions {
+                confidence_threshold: Some(min_confidence),
+                passthrough_below_threshold: true,
+                ..Default::default()
+            }),
         )
-        .unwrap();
-        let proof_regex =
-            Regex::new(r"(?i)\b(prove|verify|validate|certify|demonstrate|qed|proven)\b").unwrap();
-        let complex_regex =
-            Regex::new(r"(?i)\b(for all|there exists|if and only if|implies|therefore)\b").unwrap();
-        let api_regex =
-            Regex::new(r"(?i)\b(api|endpoint|route|controller|handler|service)\b").unwrap();
-        let contractor_regex =
-            Regex::new(r"(?i)\b(delta|invariant|precondition|postcondition|requires|ensures)\b")
-                .unwrap();
-        let intent_regex =
-            Regex::new(r"(?i)\b(intent|goal|purpose|objective|fitness|risk|utility)\b").unwrap();
+    }
 
-        let has_types = types_regex.is_match(prose);
-        let has_rules = rules_regex.is_match(prose);
-        let has_proof = proof_regex.is_match(prose);
-        let has_complex = complex_regex.is_match(prose);
-        let has_api = api_regex.is_match(prose);
-        let has_contractor = contractor_regex.is_match(prose);
-        let has_intent = intent_regex.is_match(prose);
+    /// Substitute only as much as needed to fit `max_tokens`, most compressive first
+    ///
+    /// Tokens are counted with `RosettaStone::tokenize`. Candidate substitutions
+    /// are those `RosettaStone::preview` finds; each one saves the tokens of its
+    /// phrase minus those of its symbol ("greater than" → `>` saves one, "and" →
+    /// `∧` none). They are applied in order of savings, ties going to the earlier
+    /// phrase, until the output fits; the rest stays prose. The phrase passes of
+    /// `convert` (bounds, ranges, conditionals) do not run.
+    ///
+    /// `\aisp{...}` spans stay as written, and `[[symbol:phrase]]` hints for
+    /// table symbols always become their symbol, whatever the budget.
+    ///
+    /// When even every saving substitution does not fit, all of them are applied
+    /// and `tokens` is above the budget.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// let prose = "for all x in S, x is greater than 0 and y is less than 1";
+    /// let result = AispConverter::convert_budgeted(prose, 15);
+    /// assert_eq!(result.output, "∀ x in S, x is > 0 and y is less than 1");
+    /// assert_eq!(result.tokens, 15);
+    /// assert_eq!(result.applied.len(), 2);
+    /// ```
+    pub fn convert_budgeted(prose: &str, max_tokens: usize) -> BudgetedConversion {
+        let token_count = |text: &str| RosettaStone::tokenize(text).len();
 
-        // Full tier: proofs, contractors, intents required, or types + rules together
-        if has_proof || has_contractor || has_intent || (has_types && has_rules) {
-            return ConversionTier::Full;
-        }
+        let saving = |planned: &PlannedSubstitution| {
+            token_count(&prose[planned.span.clone()]).saturating_sub(token_count(planned.symbol))
+        };
 
-        // Standard tier: types OR rules OR complex logic OR API OR longer text
-        if has_types || has_rules || has_complex || has_api || word_count > 20 {
-            return ConversionTier::Standard;
-        }
+        // Hints are explicit, so they are applied before any budgeting
+        let (hints, planned): (Vec<_>, Vec<_>) = RosettaStone::preview(prose)
+            .into_iter()
+            .partition(|planned| prose[planned.span.clone()].starts_with("[["));
 
-        // Minimal tier: simple, short prose
-        ConversionTier::Minimal
-    }
+        let mut candidates: Vec<(usize, PlannedSubstitution)> = planned
+            .into_iter()
+            .map(|planned| (saving(&planned), planned))
+            .filter(|&(saved, _)| saved > 0)
+            .collect();
+        candidates.sort_by(|(a_saved, a), (b_saved, b)| {
+            b_saved
+                .cmp(a_saved)
+                .then_with(|| a.span.start.cmp(&b.span.start))
+        });
 
-    /// Minimal conversion - direct Rosetta mapping
-    fn convert_minimal(prose: &str) -> ConversionResult {
-        let (output, mapped_chars, unmapped) = RosettaStone::convert(prose);
-        let confidence = RosettaStone::confidence(prose.len(), mapped_chars);
+        let mut tokens = token_count(prose).saturating_sub(hints.iter().map(saving).sum());
+        let mut applied = hints;
+        for (saved, planned) in candidates {
+            if tokens <= max_tokens {
+                break;
+            }
+            tokens -= saved;
+            applied.push(planned);
+        }
+        applied.sort_by_key(|planned| planned.span.start);
 
-        ConversionResult {
+        let mut output = String::with_capacity(prose.len());
+        let mut last = 0;
+        for planned in &applied {
+            output.push_str(&prose[last..planned.span.start]);
+            output.push_str(planned.symbol);
+            last = planned.span.end;
+        }
+        output.push_str(&prose[last..]);
+
+        BudgetedConversion {
+            tokens: token_count(&output),
             output,
-            confidence,
-            unmapped,
-            tier: ConversionTier::Minimal,
-            tokens: TokenStats {
-                input: 0,
-                output: 0,
-                ratio: 0.0,
-            },
-            used_fallback: false,
+            applied,
         }
     }
 
-    /// Standard conversion - minimal + header + evidence
-    fn convert_standard(prose: &str) -> ConversionResult {
-        let minimal = Self::convert_minimal(prose);
-        let domain = Self::extract_domain(prose);
-        let date = Utc::now().format("%Y-%m-%d").to_string();
-
-        let output = format!(
-            r#"𝔸5.1.{domain}@{date}
-γ≔{domain}
-
-⟦Ω:Meta⟧{{
-  domain≜{domain}
-  version≜1.0.0
-}}
+    /// Convert prose to AISP with a fixed header date for reproducible output
+    ///
+    /// Uses `options.date` if set, otherwise the Unix epoch, so Standard/Full
+    /// documents can be compared byte-for-byte in snapshot and property tests.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionOptions, ConversionTier};
+    ///
+    /// let opts = ConversionOptions {
+    ///     tier: Some(ConversionTier::Standard),
+    ///     ..Default::default()
+    /// };
+    /// let result = AispConverter::convert_deterministic("Define x as 5", Some(opts));
+    /// assert!(result.output.starts_with("𝔸5.1.domain@1970-01-01"));
+    /// ```
+    pub fn convert_deterministic(
+        prose: &str,
+        options: Option<ConversionOptions>,
+    ) -> ConversionResult {
+        let opts = options.unwrap_or_default();
+        Self::convert(
+            prose,
+            Some(ConversionOptions {
+                date: Some(opts.date.unwrap_or(DateTime::UNIX_EPOCH)),
+                ..opts
+            }),
+        )
+    }
 
-⟦Σ:Types⟧{{
-  ∅
-}}
+    /// Convert prose to AISP with one clause per source sentence
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// let result = AispConverter::convert_clauses("Define x as 5. x is at most 10.", "\n");
+    /// assert_eq!(result.output, "x≜5\nx≤10");
+    /// ```
+    pub fn convert_clauses(prose: &str, separator: &str) -> ConversionResult {
+        Self::convert(
+            prose,
+            Some(ConversionOptions {
+                clause_separator: Some(separator.to_string()),
+                ..Default::default()
+            }),
+        )
+    }
 
-⟦Γ:Rules⟧{{
-  ∅
-}}
+    /// Score a conversion on coverage and reversibility at once
+    ///
+    /// Converts at the Minimal tier, then back with `RosettaStone::to_prose`.
+    /// Coverage and round-trip fidelity count equally in `combined` (see
+    /// `QualityScore::CONFIDENCE_WEIGHT`): prose that maps fully but comes back
+    /// changed scores no better than prose that round-trips but barely maps.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// let score = AispConverter::quality_score("for all x in S");
+    /// assert!(score.roundtrip_fidelity > 0.99);
+    /// assert!(score.combined > 0.5);
+    ///
+    /// let score = AispConverter::quality_score("the widget glows");
+    /// assert_eq!(score.forward_confidence, 0.0);
+    /// assert_eq!(score.combined, 0.5);
+    /// ```
+    pub fn quality_score(prose: &str) -> QualityScore {
+        let result = Self::convert(
+            prose,
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Minimal),
+                ..Default::default()
+            }),
+        );
+        let forward_confidence = result.confidence;
+        let roundtrip_fidelity =
+            RosettaStone::semantic_similarity(prose, &RosettaStone::to_prose(&result.output));
 
-⟦Λ:Funcs⟧{{
-  {body}
-}}
+        QualityScore {
+            forward_confidence,
+            roundtrip_fidelity,
+            combined: QualityScore::CONFIDENCE_WEIGHT * forward_confidence
+                + QualityScore::FIDELITY_WEIGHT * roundtrip_fidelity,
+        }
+    }
 
-⟦Ε⟧⟨δ≜0.70;τ≜◊⁺⟩"#,
-            domain = domain,
-            date = date,
-            body = minimal.output
+    /// Convert prose to AISP, failing when confidence is below the threshold
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionError};
+    ///
+    /// let result = AispConverter::try_convert("Lorem ipsum dolor", None);
+    /// assert!(matches!(result, Err(ConversionError::BelowThreshold { .. })));
+    /// ```
+    pub fn try_convert(
+        prose: &str,
+        options: Option<ConversionOptions>,
+    ) -> Result<ConversionResult, ConversionError> {
+        let threshold = options.as_ref().map_or(
+            ConversionOptions::DEFAULT_CONFIDENCE_THRESHOLD,
+            ConversionOptions::threshold,
         );
+        let result = Self::convert(prose, options);
 
-        ConversionResult {
-            output,
-            confidence: minimal.confidence,
-            unmapped: minimal.unmapped,
-            tier: ConversionTier::Standard,
-            tokens: TokenStats {
-                input: 0,
-                output: 0,
-                ratio: 0.0,
-            },
-            used_fallback: false,
+        if result.below_threshold {
+            return Err(ConversionError::BelowThreshold {
+                confidence: result.confidence,
+                threshold,
+            });
         }
+        Ok(result)
     }
 
-    /// Full conversion - complete AISP document
-    fn convert_full(prose: &str) -> ConversionResult {
-        let minimal = Self::convert_minimal(prose);
+    /// Convert prose to AISP and check the output with `aisp::validate`
+    ///
+    /// Standard and Full documents are validated as generated. Minimal output
+    /// is a bare expression, so it is validated as the `⟦Λ:Funcs⟧` body of an
+    /// otherwise empty document. On failure the error carries the validator's
+    /// result, and each block is re-validated under the document header to
+    /// name the first one that fails alone.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{
+    ///     AispConverter, ConversionOptions, ConversionTier, FullBlock, FullTemplate, ValidationError,
+    /// };
+    ///
+    /// let result = AispConverter::convert_checked("Define x as 5", None).unwrap();
+    /// assert_eq!(result.tier, ConversionTier::Minimal);
+    ///
+    /// // A Full document without its `⟦Λ:Funcs⟧` block is missing a required block
+    /// let opts = ConversionOptions {
+    ///     tier: Some(ConversionTier::Full),
+    ///     full_template: FullTemplate::default().without_block(FullBlock::Funcs),
+    ///     ..Default::default()
+    /// };
+    /// match AispConverter::convert_checked("Define x as 5", Some(opts)) {
+    ///     Err(ValidationError::Invalid { report, .. }) => {
+    ///         assert_eq!(report.error, Some("Missing required blocks"));
+    ///     }
+    ///     Ok(_) => unreachable!(),
+    /// }
+    /// ```
+    pub fn convert_checked(
+        prose: &str,
+        options: Option<ConversionOptions>,
+    ) -> Result<ConversionResult, ValidationError> {
+        let result = Self::convert(prose, options);
+        let document = if result.tier == ConversionTier::Minimal {
+            Self::expression_document(&result.output)
+        } else {
+            result.output.clone()
+        };
+
+        let report = Self::validate(&document);
+        if report.valid {
+            return Ok(result);
+        }
+
+        Err(ValidationError::Invalid {
+            block: Self::failing_block(&document),
+            report,
+        })
+    }
+
+    /// Convert prose to AISP and check the output with `aisp::validate`
+    ///
+    /// Like `convert_checked`, with the validator's error message in a
+    /// `ConversionError::Invalid` so it shares an error type with `try_convert`.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionOptions, ConversionTier};
+    ///
+    /// let opts = ConversionOptions {
+    ///     tier: Some(ConversionTier::Full),
+    ///     ..Default::default()
+    /// };
+    /// let result = AispConverter::convert_validated("Define x as 5", Some(opts)).unwrap();
+    /// assert!(result.output.contains("⟦Σ:Types⟧"));
+    /// ```
+    pub fn convert_validated(
+        prose: &str,
+        options: Option<ConversionOptions>,
+    ) -> Result<ConversionResult, ConversionError> {
+        Ok(Self::convert_checked(prose, options)?)
+    }
+
+    /// A document holding a Minimal expression as its only function body, for validation
+    fn expression_document(expression: &str) -> String {
+        format!(
+            "𝔸5.1.expression\nγ≔expression\n\n⟦Ω:Meta⟧{{\n  ∅\n}}\n\n⟦Σ:Types⟧{{\n  ∅\n}}\n\n⟦Γ:Rules⟧{{\n  ∅\n}}\n\n⟦Λ:Funcs⟧{{\n  {}\n}}\n\n⟦Ε⟧⟨δ≜0.70;τ≜◊⁺⟩",
+            expression.replace('\n', "\n  ")
+        )
+    }
+
+    /// Header of the first `⟦…⟧` block that fails validation alongside the document header
+    fn failing_block(aisp: &str) -> Option<String> {
+        let block_start = Regex::new(r"(?m)^⟦[^⟧]*⟧").unwrap();
+        let starts: Vec<_> = block_start.find_iter(aisp).collect();
+        let header = starts.first().map_or(aisp, |m| &aisp[..m.start()]);
+
+        starts.iter().enumerate().find_map(|(i, start)| {
+            let end = starts.get(i + 1).map_or(aisp.len(), |next| next.start());
+            let document = format!("{}{}", header, &aisp[start.start()..end]);
+            (!Self::validate(&document).valid).then(|| start.as_str().to_string())
+        })
+    }
+
+    /// Auto-detect appropriate tier based on prose complexity
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionTier};
+    ///
+    /// assert_eq!(AispConverter::detect_tier("Define x as 5"), ConversionTier::Minimal);
+    /// assert_eq!(
+    ///     AispConverter::detect_tier("The user must authenticate to access the API"),
+    ///     ConversionTier::Standard
+    /// );
+    /// ```
+    pub fn detect_tier(prose: &str) -> ConversionTier {
+        Self::detect_tier_with_policy(prose, TierPolicy::default())
+    }
+
+    /// Auto-detect a tier with custom thresholds and trigger words
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionTier, TierPolicy};
+    ///
+    /// let prose = "one two three four five six seven eight nine ten \
+    ///              one two three four five six seven eight nine ten more";
+    /// assert_eq!(AispConverter::detect_tier(prose), ConversionTier::Standard);
+    ///
+    /// let relaxed = TierPolicy {
+    ///     standard_word_count: 50,
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(
+    ///     AispConverter::detect_tier_with_policy(prose, relaxed),
+    ///     ConversionTier::Minimal
+    /// );
+    /// ```
+    pub fn detect_tier_with_policy(prose: &str, policy: TierPolicy) -> ConversionTier {
+        Self::detect_tier_reasons(prose, &policy).0
+    }
+
+    /// Detected tier and the triggers that chose it
+    fn detect_tier_reasons(prose: &str, policy: &TierPolicy) -> (ConversionTier, Vec<String>) {
+        let word_count = prose.split_whitespace().count();
+        let trigger = |words: &[String], kind: &str| {
+            TierPolicy::first_mention(prose, words).map(|word| format!("{} \"{}\"", kind, word))
+        };
+
+        let types = trigger(&policy.type_words, "type word");
+        let rules = trigger(&policy.rule_words, "rule word");
+
+        // Full tier: proofs, contractors, intents required, or types + rules together
+        let mut full: Vec<String> = [
+            trigger(&policy.proof_words, "proof word"),
+            trigger(&policy.contractor_words, "contractor word"),
+            trigger(&policy.intent_words, "intent word"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if let (Some(ty), Some(rule)) = (&types, &rules) {
+            full.push(format!("{} with {}", ty, rule));
+        }
+        if !full.is_empty() {
+            return (ConversionTier::Full, full);
+        }
+
+        // Standard tier: types OR rules OR complex logic OR API OR longer text
+        let mut standard: Vec<String> = [
+            types,
+            rules,
+            trigger(&policy.complex_words, "complex logic word"),
+            trigger(&policy.api_words, "API word"),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if word_count > policy.standard_word_count {
+            standard.push(format!(
+                "more than {} words ({})",
+                policy.standard_word_count, word_count
+            ));
+        }
+        if !standard.is_empty() {
+            return (ConversionTier::Standard, standard);
+        }
+
+        // Minimal tier: simple, short prose
+        (
+            ConversionTier::Minimal,
+            vec![format!("{} words with no tier triggers", word_count)],
+        )
+    }
+
+    /// The tier `convert` would use for this prose, and why, without converting it
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionTier};
+    ///
+    /// let (tier, reasons) = AispConverter::plan("The user must authenticate");
+    /// assert_eq!(tier, ConversionTier::Standard);
+    /// assert_eq!(reasons, vec!["rule word \"must\""]);
+    /// ```
+    pub fn plan(prose: &str) -> (ConversionTier, Vec<String>) {
+        Self::plan_with_options(prose, None)
+    }
+
+    /// The tier `convert` would use with these options, and why, without converting
+    ///
+    /// Follows `convert`: empty input is Minimal, AISP input keeps its own tier
+    /// unless a higher one is requested, and a requested tier wins over detection.
+    pub fn plan_with_options(
+        prose: &str,
+        options: Option<ConversionOptions>,
+    ) -> (ConversionTier, Vec<String>) {
+        let opts = options.unwrap_or_default();
+
+        if prose.trim().is_empty() {
+            return (ConversionTier::Minimal, vec!["empty input".to_string()]);
+        }
+
+        if Self::is_converted(prose, &opts) {
+            let current = Self::classify_tier(prose);
+            if opts.tier.is_none_or(|tier| tier <= current) {
+                return (
+                    current,
+                    vec![format!("already AISP at the {} tier", current)],
+                );
+            }
+        }
+
+        match opts.tier {
+            Some(tier) => (tier, vec![format!("{} tier requested in options", tier)]),
+            None => Self::detect_tier_reasons(prose, &opts.tier_policy.unwrap_or_default()),
+        }
+    }
+
+    /// Hints for what prose needs to auto-detect as `target`
+    ///
+    /// Each hint is an alternative: following any one of them reaches the
+    /// target tier. Empty when the prose already detects at `target` or above.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionTier};
+    ///
+    /// let hints = AispConverter::tier_requirements("Define x as 5", ConversionTier::Standard);
+    /// assert!(hints.iter().any(|hint| hint.contains("\"must\"")));
+    /// assert!(AispConverter::tier_requirements("Define x as 5", ConversionTier::Minimal).is_empty());
+    /// ```
+    pub fn tier_requirements(prose: &str, target: ConversionTier) -> Vec<String> {
+        Self::tier_requirements_with_policy(prose, target, TierPolicy::default())
+    }
+
+    /// Hints for what prose needs to auto-detect as `target` under a custom policy
+    pub fn tier_requirements_with_policy(
+        prose: &str,
+        target: ConversionTier,
+        policy: TierPolicy,
+    ) -> Vec<String> {
+        if Self::detect_tier_with_policy(prose, policy.clone()) >= target {
+            return Vec::new();
+        }
+
+        let example = |words: &[String]| words.first().map(|w| format!("\"{}\"", w));
+        let mut hints = Vec::new();
+        let mut hint = |words: &[String], text: &str| {
+            if let Some(word) = example(words) {
+                hints.push(format!("{} such as {}", text, word));
+            }
+        };
+
+        match target {
+            ConversionTier::Minimal => {}
+            ConversionTier::Standard => {
+                hint(&policy.rule_words, "add a rule");
+                hint(&policy.type_words, "mention a type");
+                hint(&policy.complex_words, "state complex logic");
+                hint(&policy.api_words, "mention an API element");
+                hints.push(format!(
+                    "write more than {} words (currently {})",
+                    policy.standard_word_count,
+                    prose.split_whitespace().count()
+                ));
+            }
+            ConversionTier::Full => {
+                hint(&policy.proof_words, "ask for a proof");
+                hint(&policy.contractor_words, "state a contract");
+                hint(&policy.intent_words, "name an intent");
+
+                // A type and a rule together also qualify
+                let has_types = TierPolicy::mentions(prose, &policy.type_words);
+                let has_rules = TierPolicy::mentions(prose, &policy.rule_words);
+                match (
+                    has_types,
+                    has_rules,
+                    example(&policy.type_words),
+                    example(&policy.rule_words),
+                ) {
+                    (true, false, _, Some(rule)) => {
+                        hints.push(format!("add a rule such as {} alongside the type", rule))
+                    }
+                    (false, true, Some(ty), _) => {
+                        hints.push(format!("mention a type such as {} alongside the rule", ty))
+                    }
+                    (false, false, Some(ty), Some(rule)) => hints.push(format!(
+                        "mention a type such as {} and add a rule such as {}",
+                        ty, rule
+                    )),
+                    _ => {}
+                }
+            }
+        }
+        hints
+    }
+
+    /// Classify the tier an AISP document was produced at, from its block markers
+    ///
+    /// The inverse of [`detect_tier`](Self::detect_tier): Meta, Funcs, and Evidence
+    /// blocks make a Standard document; Full additionally needs Types, Rules, Errors,
+    /// and a proof marker (`⊢` or `∎`).
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionTier};
+    ///
+    /// assert_eq!(AispConverter::classify_tier("∀x∈S:x≥0"), ConversionTier::Minimal);
+    /// ```
+    pub fn classify_tier(aisp: &str) -> ConversionTier {
+        let has_block = |marker: &str| aisp.contains(marker);
+        let is_standard = has_block("⟦Ω") && has_block("⟦Λ") && has_block("⟦Ε");
+
+        if is_standard
+            && has_block("⟦Σ")
+            && has_block("⟦Γ")
+            && has_block("⟦Χ")
+            && (aisp.contains('⊢') || aisp.contains('∎'))
+        {
+            return ConversionTier::Full;
+        }
+
+        if is_standard {
+            return ConversionTier::Standard;
+        }
+
+        ConversionTier::Minimal
+    }
+
+    /// Whether input counts as already converted; with `preserve_symbols` only documents do
+    fn is_converted(prose: &str, opts: &ConversionOptions) -> bool {
+        if opts.preserve_symbols {
+            let trimmed = prose.trim_start();
+            trimmed.starts_with('𝔸') || trimmed.contains('⟦')
+        } else {
+            RosettaStone::is_aisp(prose)
+        }
+    }
+
+    /// Result for input that is already AISP at `tier`
+    fn already_converted(aisp: &str, tier: ConversionTier) -> ConversionResult {
+        let output = aisp.trim().to_string();
+        ConversionResult {
+            tokens: TokenStats::measure(aisp, &output),
+            output,
+            confidence: 1.0,
+            category_confidence: HashMap::new(),
+            confidence_weighted: 1.0,
+            unmapped: Vec::new(),
+            fuzzy_candidates: Vec::new(),
+            skipped_substitutions: Vec::new(),
+            pattern_counts: HashMap::new(),
+            warnings: Vec::new(),
+            tier,
+            used_fallback: false,
+            below_threshold: false,
+            passthrough: false,
+            symbol_share: RosettaStone::symbol_share(aisp),
+        }
+    }
+
+    /// Minimal conversion - direct Rosetta mapping
+    fn convert_minimal(prose: &str, opts: &ConversionOptions) -> ConversionResult {
+        let source = Self::prepare_source(prose, opts);
+        let (mut output, applied, unmapped) = if opts.line_as_clause {
+            Self::convert_lines(&source, opts)
+        } else {
+            Self::convert_source(&source, opts)
+        };
+        if opts.annotate_source {
+            output = Self::annotate_sentences(prose, opts);
+        }
+        let category_chars = RosettaStone::tally_categories(&applied);
+        let mapped_chars = category_chars.values().sum();
+        let confidence = RosettaStone::confidence(prose.len(), mapped_chars);
+        let category_confidence = RosettaStone::category_confidence(prose.len(), &category_chars);
+        let confidence_weighted = RosettaStone::weighted_confidence(prose.len(), &applied);
+        #[cfg(feature = "fuzzy")]
+        let fuzzy_candidates = RosettaStone::fuzzy_candidates(&unmapped)
+            .into_iter()
+            .map(|(word, pattern)| (word, pattern.to_string()))
+            .collect();
+        #[cfg(not(feature = "fuzzy"))]
+        let fuzzy_candidates = Vec::new();
+        let skipped_substitutions = match opts.mode {
+            ConversionMode::Compact => Vec::new(),
+            ConversionMode::Lossless => RosettaStone::lossy_substitutions(&source)
+                .into_iter()
+                .map(|sub| (sub.matched, sub.symbol.to_string()))
+                .collect(),
+        };
+
+        ConversionResult {
+            output,
+            confidence,
+            category_confidence,
+            confidence_weighted,
+            unmapped,
+            fuzzy_candidates,
+            skipped_substitutions,
+            pattern_counts: Self::count_patterns(&applied),
+            warnings: Self::parenthesis_warnings(prose, 0),
+            tier: ConversionTier::Minimal,
+            tokens: TokenStats::default(),
+            used_fallback: false,
+            below_threshold: false,
+            passthrough: false,
+            symbol_share: RosettaStone::symbol_share(prose),
+        }
+    }
+
+    /// Standard conversion - minimal + header + inferred types and rules + evidence
+    ///
+    /// Types and rules come from the same inference as Full, without Full's
+    /// placeholders: a block with nothing inferred is `∅`.
+    fn convert_standard(prose: &str, opts: &ConversionOptions) -> ConversionResult {
+        let minimal = Self::convert_minimal(prose, opts);
         let domain = Self::extract_domain(prose);
-        let date = Utc::now().format("%Y-%m-%d").to_string();
-        let types = Self::infer_types(prose);
-        let rules = Self::infer_rules(prose);
-        let errors = Self::infer_errors(prose);
+        let date = opts
+            .date
+            .unwrap_or_else(Utc::now)
+            .format("%Y-%m-%d")
+            .to_string();
 
-        let output = format!(
-            r#"𝔸5.1.{domain}@{date}
-γ≔{domain}.definitions
-ρ≔⟨{domain},types,rules⟩
+        let blocks = Self::render_blocks(
+            vec![
+                ("Ω:Meta", format!("  domain≜{}\n  version≜1.0.0", domain)),
+                ("Σ:Types", Self::block_body(Self::inferred_types(prose))),
+                ("Γ:Rules", Self::block_body(Self::inferred_rules(prose))),
+                (
+                    "Λ:Funcs",
+                    format!("  {}", minimal.output.replace('\n', "\n  ")),
+                ),
+            ],
+            opts,
+        );
 
-⟦Ω:Meta⟧{{
-  domain≜{domain}
-  version≜1.0.0
-  ∀D∈AISP:Ambig(D)<0.02
-}}
+        let output = format!(
+            "𝔸5.1.{domain}@{date}\nγ≔{domain}\n\n{blocks}\n\n⟦Ε⟧⟨δ≜0.70;τ≜◊⁺⟩",
+            domain = domain,
+            date = date,
+            blocks = blocks,
+        );
 
-⟦Σ:Types⟧{{
-{types}
-}}
+        ConversionResult {
+            output,
+            confidence: minimal.confidence,
+            category_confidence: minimal.category_confidence,
+            confidence_weighted: minimal.confidence_weighted,
+            skipped_substitutions: minimal.skipped_substitutions,
+            pattern_counts: minimal.pattern_counts,
+            warnings: minimal.warnings,
+            unmapped: minimal.unmapped,
+            fuzzy_candidates: minimal.fuzzy_candidates,
+            tier: ConversionTier::Standard,
+            tokens: TokenStats::default(),
+            used_fallback: false,
+            below_threshold: false,
+            passthrough: false,
+            symbol_share: minimal.symbol_share,
+        }
+    }
 
-⟦Γ:Rules⟧{{
-{rules}
-}}
+    /// Full conversion - complete AISP document
+    fn convert_full(prose: &str, opts: &ConversionOptions) -> ConversionResult {
+        let minimal = Self::convert_minimal(prose, opts);
+        let domain = Self::extract_domain(prose);
+        let date = opts
+            .date
+            .unwrap_or_else(Utc::now)
+            .format("%Y-%m-%d")
+            .to_string();
+        let template = &opts.full_template;
 
-⟦Λ:Funcs⟧{{
-  {body}
-}}
+        let blocks = Self::render_blocks(
+            template
+                .blocks
+                .iter()
+                .map(|block| match block {
+                    FullBlock::Meta => (
+                        "Ω:Meta",
+                        format!(
+                            "  domain≜{}\n  version≜1.0.0\n  ∀D∈AISP:Ambig(D)<0.02",
+                            domain
+                        ),
+                    ),
+                    FullBlock::Types => ("Σ:Types", Self::infer_types(prose)),
+                    FullBlock::Rules => ("Γ:Rules", Self::infer_rules(prose)),
+                    FullBlock::Funcs => (
+                        "Λ:Funcs",
+                        format!("  {}", minimal.output.replace('\n', "\n  ")),
+                    ),
+                    FullBlock::Errors => ("Χ:Errors", Self::infer_errors(prose)),
+                    FullBlock::Custom { header, body } => (header.as_str(), body.clone()),
+                })
+                .collect(),
+            opts,
+        );
 
-⟦Χ:Errors⟧{{
-{errors}
-}}
+        // Implication chains in the prose become the proof; otherwise a bare ⊢valid
+        let proof = Self::infer_proof(prose).unwrap_or_else(|| "⊢valid".to_string());
 
-⟦Ε⟧⟨δ≜0.82;φ≜100;τ≜◊⁺⁺;⊢valid;∎⟩"#,
+        let output = format!(
+            "𝔸5.1.{domain}@{date}\nγ≔{domain}.definitions\nρ≔⟨{domain},types,rules⟩\n\n{blocks}\n\n⟦Ε⟧⟨δ≜{delta:.2};φ≜{phi};τ≜{tau};{proof};∎⟩",
             domain = domain,
             date = date,
-            types = types,
-            rules = rules,
-            body = minimal.output,
-            errors = errors
+            blocks = blocks,
+            proof = proof,
+            delta = template.delta,
+            phi = template.phi,
+            tau = template.tau,
         );
 
         ConversionResult {
             output,
             confidence: minimal.confidence,
+            category_confidence: minimal.category_confidence,
+            confidence_weighted: minimal.confidence_weighted,
+            skipped_substitutions: minimal.skipped_substitutions,
+            pattern_counts: minimal.pattern_counts,
+            warnings: minimal.warnings,
             unmapped: minimal.unmapped,
+            fuzzy_candidates: minimal.fuzzy_candidates,
             tier: ConversionTier::Full,
-            tokens: TokenStats {
-                input: 0,
-                output: 0,
-                ratio: 0.0,
-            },
+            tokens: TokenStats::default(),
             used_fallback: false,
+            below_threshold: false,
+            passthrough: false,
+            symbol_share: minimal.symbol_share,
+        }
+    }
+
+    /// Render `⟦header⟧{body}` blocks separated by blank lines
+    /// With `omit_empty_blocks`, blocks whose body is just `∅` are dropped
+    fn render_blocks(blocks: Vec<(&str, String)>, opts: &ConversionOptions) -> String {
+        blocks
+            .into_iter()
+            .filter(|(_, body)| !(opts.omit_empty_blocks && body.trim() == "∅"))
+            .map(|(header, body)| format!("⟦{}⟧{{\n{}\n}}", header, body))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    /// Rosetta pass options derived from conversion options
+    fn rosetta_options(opts: &ConversionOptions) -> RosettaOptions {
+        RosettaOptions {
+            normalize_identifiers: opts.normalize_identifiers,
+            exclude_identifiers: opts.exclude_identifiers,
+            mode: opts.mode,
+            preserve_typography: opts.preserve_typography,
+            preserve_symbols: opts.preserve_symbols,
+            symbolize_comparatives: opts.symbolize_comparatives,
+        }
+    }
+
+    /// Apply opt-in input normalization before matching
+    fn prepare_source(prose: &str, opts: &ConversionOptions) -> String {
+        let mut source = if opts.split_concatenated {
+            RosettaStone::split_concatenated(prose)
+        } else {
+            prose.to_string()
+        };
+        if opts.normalize_numerals {
+            source = RosettaStone::normalize_numerals(&source);
+        }
+        if opts.singularize {
+            RosettaStone::singularize(&source)
+        } else {
+            source
+        }
+    }
+
+    /// Run the Rosetta pass over prepared source, by sentence when a clause separator is set
+    fn convert_source(
+        source: &str,
+        opts: &ConversionOptions,
+    ) -> (String, Vec<Substitution>, Vec<String>) {
+        match &opts.clause_separator {
+            Some(separator) => Self::convert_sentences(source, separator, opts),
+            None => RosettaStone::convert_detailed(source, &Self::rosetta_options(opts)),
         }
     }
 
+    /// Convert each line separately, keeping its indentation
+    fn convert_lines(
+        source: &str,
+        opts: &ConversionOptions,
+    ) -> (String, Vec<Substitution>, Vec<String>) {
+        let mut applied = Vec::new();
+        let mut unmapped = Vec::new();
+
+        let lines: Vec<String> = source
+            .lines()
+            .map(|line| {
+                let text = line.trim_start();
+                let indent = &line[..line.len() - text.len()];
+                let (converted, subs, words) = Self::convert_source(text, opts);
+                applied.extend(subs);
+                unmapped.extend(words);
+                if converted.is_empty() {
+                    converted
+                } else {
+                    format!("{}{}", indent, converted)
+                }
+            })
+            .collect();
+
+        unmapped.sort();
+        unmapped.dedup();
+        (lines.join("\n"), applied, unmapped)
+    }
+
+    /// Convert each sentence as a separate clause, without its terminator
+    fn convert_sentences(
+        prose: &str,
+        separator: &str,
+        opts: &ConversionOptions,
+    ) -> (String, Vec<Substitution>, Vec<String>) {
+        let mut applied = Vec::new();
+        let mut unmapped = Vec::new();
+
+        let clauses: Vec<String> = RosettaStone::split_sentences(prose)
+            .into_iter()
+            .map(|sentence| {
+                let clause = sentence.trim_end_matches(['.', '!', '?']);
+                let (converted, subs, words) =
+                    RosettaStone::convert_detailed(clause, &Self::rosetta_options(opts));
+                applied.extend(subs);
+                unmapped.extend(words);
+                converted
+            })
+            .filter(|clause| !clause.is_empty())
+            .collect();
+
+        unmapped.sort();
+        unmapped.dedup();
+        (clauses.join(separator), applied, unmapped)
+    }
+
+    /// Convert each sentence and follow it with its source as `⟨prose:"..."⟩`
+    fn annotate_sentences(prose: &str, opts: &ConversionOptions) -> String {
+        RosettaStone::split_sentences(prose)
+            .into_iter()
+            .map(|sentence| {
+                let (converted, _, _) = RosettaStone::convert_with(
+                    &Self::prepare_source(sentence, opts),
+                    &Self::rosetta_options(opts),
+                );
+                let escaped = sentence.replace('\\', "\\\\").replace('"', "\\\"");
+                format!("{} ⟨prose:\"{}\"⟩", converted, escaped)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
     /// Extract domain from prose
     fn extract_domain(prose: &str) -> &'static str {
         let lower = prose.to_lowercase();
@@ -319,8 +1717,26 @@ impl AispConverter {
         "domain"
     }
 
-    /// Infer types from prose
+    /// Block body of one item per line, or `∅` when there are none
+    fn block_body(items: Vec<&str>) -> String {
+        if items.is_empty() {
+            "  ∅".to_string()
+        } else {
+            items.join("\n")
+        }
+    }
+
+    /// Infer types from prose, with a placeholder type when none are mentioned
     fn infer_types(prose: &str) -> String {
+        let mut types = Self::inferred_types(prose);
+        if types.is_empty() {
+            types.push("  T≜⟨value:Any⟩");
+        }
+        types.join("\n")
+    }
+
+    /// Types the prose mentions
+    fn inferred_types(prose: &str) -> Vec<&'static str> {
         let lower = prose.to_lowercase();
         let mut types = Vec::new();
 
@@ -347,56 +1763,57 @@ impl AispConverter {
             types.push("  List⟨T⟩≜⟨items:T*⟩");
         }
 
-        if types.is_empty() {
-            types.push("  T≜⟨value:Any⟩");
-        }
-
-        types.join("\n")
+        types
     }
 
-    /// Infer rules from prose
+    /// Infer rules from prose, with a placeholder rule when none are triggered
     fn infer_rules(prose: &str) -> String {
+        let mut rules = Self::inferred_rules(prose);
+        if rules.is_empty() {
+            rules.push("  ∀x:T:⊤");
+        }
+        rules.join("\n")
+    }
+
+    /// Rules the prose triggers
+    ///
+    /// Rules are deduplicated and emitted in `RULE_PRIORITY` order,
+    /// so the same prose always yields the same block.
+    fn inferred_rules(prose: &str) -> Vec<&'static str> {
         let lower = prose.to_lowercase();
-        let mut rules = Vec::new();
 
-        if lower.contains("constant") || lower.contains("immutable") {
-            rules.push("  ∀c∈Const:c.immutable≡⊤");
-        }
-        if lower.contains("valid") || lower.contains("check") {
-            rules.push("  ∀x:T:valid(x)⇒accept(x)");
-        }
-        if lower.contains("all") || lower.contains("every") {
-            rules.push("  ∀x∈S:P(x)");
-        }
-        if lower.contains("must") || lower.contains("require") {
-            rules.push("  ∀x:T:require(x)⇒proceed(x)");
-        }
-        if lower.contains("unique") {
-            rules.push("  ∃!x:T:unique(x)");
-        }
-        if lower.contains("admin") {
-            rules.push("  ∀u∈User:u.admin≡⊤⇒allow(u)");
-        }
+        let mut rules: Vec<&str> = Vec::new();
 
-        // Contractor detections
-        if lower.contains("invariant") || lower.contains("always true") {
-            rules.push("  Inv(s)≜always(s)");
-        }
-        if lower.contains("precondition") || lower.contains("before") {
-            rules.push("  Pre(f)≜req(args)");
-        }
-        if lower.contains("postcondition") || lower.contains("after") || lower.contains("ensures") {
-            rules.push("  Post(f)≜guarantee(result)");
-        }
-        if lower.contains("delta") || lower.contains("change") {
-            rules.push("  Δ(s)≜s'−s");
+        for (triggers, rule) in RULE_PRIORITY {
+            if triggers.iter().any(|t| lower.contains(t)) && !rules.contains(rule) {
+                rules.push(rule);
+            }
         }
 
-        if rules.is_empty() {
-            rules.push("  ∀x:T:⊤");
+        rules
+    }
+
+    /// Tally how many times each pattern matched
+    pub(crate) fn count_patterns(applied: &[Substitution]) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for sub in applied {
+            *counts.entry(sub.pattern.to_string()).or_insert(0) += 1;
         }
+        counts
+    }
 
-        rules.join("\n")
+    /// Warn about each unmatched parenthesis in `text`, found at `offset` in the input
+    pub(crate) fn parenthesis_warnings(text: &str, offset: usize) -> Vec<String> {
+        RosettaStone::unbalanced_parentheses(text)
+            .into_iter()
+            .map(|i| {
+                format!(
+                    "unbalanced parentheses: unmatched '{}' at byte {}",
+                    &text[i..i + 1],
+                    offset + i
+                )
+            })
+            .collect()
     }
 
     /// Infer errors from prose
@@ -428,8 +1845,94 @@ impl AispConverter {
         errors.join("\n")
     }
 
+    /// Infer a proof sketch from implication chains in prose
+    ///
+    /// "A implies B, B implies C, therefore A implies C" → `π≜A⇒B∧B⇒C;⊢A⇒C`.
+    /// A stated conclusion is kept only if the premises derive it; without one,
+    /// the conclusion of the longest chain is derived. None when no chain of at
+    /// least two premises links up.
+    fn infer_proof(prose: &str) -> Option<String> {
+        let clause =
+            Regex::new(r"(?i)^(?:and\s+)?(therefore|thus|hence)?\s*(.+?)\s+implies\s+(.+)$")
+                .unwrap();
+        let marker = Regex::new(r"(?i)\s+(?:and\s+)?\b(therefore|thus|hence)\b").unwrap();
+        let split = marker.replace_all(prose, ", $1");
+
+        let mut premises: Vec<(String, String)> = Vec::new();
+        let mut conclusions: Vec<(String, String)> = Vec::new();
+        for part in split.split([',', ';', '.']) {
+            if let Some(caps) = clause.captures(part.trim()) {
+                let edge = (caps[2].to_string(), caps[3].to_string());
+                if caps.get(1).is_some() {
+                    conclusions.push(edge);
+                } else {
+                    premises.push(edge);
+                }
+            }
+        }
+
+        // Terms match case-insensitively
+        let same = |a: &str, b: &str| a.to_lowercase() == b.to_lowercase();
+
+        // Premises leading from `from` to `to`, shortest first
+        let derive = |from: &str, to: &str| -> Option<Vec<usize>> {
+            let mut paths: Vec<(String, Vec<usize>)> = vec![(from.to_string(), Vec::new())];
+            while let Some((at, path)) = paths.first().cloned() {
+                paths.remove(0);
+                for (i, (a, b)) in premises.iter().enumerate() {
+                    if same(a, &at) && !path.contains(&i) {
+                        let mut next = path.clone();
+                        next.push(i);
+                        if same(b, to) {
+                            return Some(next);
+                        }
+                        paths.push((b.clone(), next));
+                    }
+                }
+            }
+            None
+        };
+
+        // Without a stated conclusion, derive the longest chain's endpoints
+        if conclusions.is_empty() {
+            let longest = premises
+                .iter()
+                .flat_map(|(a, _)| premises.iter().map(move |(_, b)| (a, b)))
+                .filter(|(a, b)| !same(a, b))
+                .filter_map(|(a, b)| derive(a, b).map(|path| (path, a.clone(), b.clone())))
+                .max_by_key(|(path, _, _)| path.len())?;
+            conclusions.push((longest.1, longest.2));
+        }
+
+        let symbolize = |term: &str| RosettaStone::convert(term).0;
+        let implication = |(a, b): &(String, String)| format!("{}⇒{}", symbolize(a), symbolize(b));
+
+        let mut steps = Vec::new();
+        let mut proven = Vec::new();
+        for conclusion in &conclusions {
+            let Some(path) = derive(&conclusion.0, &conclusion.1).filter(|p| p.len() > 1) else {
+                continue;
+            };
+            for i in path {
+                let step = implication(&premises[i]);
+                if !steps.contains(&step) {
+                    steps.push(step);
+                }
+            }
+            proven.push(format!("⊢{}", implication(conclusion)));
+        }
+
+        if proven.is_empty() {
+            return None;
+        }
+        Some(format!("π≜{};{}", steps.join("∧"), proven.join(";")))
+    }
+
     /// Convert AISP back to prose
     ///
+    /// Line and paragraph breaks are kept, so Standard/Full documents
+    /// retain their block layout.
+    ///
     /// # Example
     /// ```
     /// use rosetta_aisp::AispConverter;
@@ -439,18 +1942,69 @@ impl AispConverter {
     /// assert!(prose.contains("in"));
     /// ```
     pub fn to_prose(aisp: &str) -> String {
-        RosettaStone::to_prose(aisp)
-    }
-
-    /// Validate AISP document using the aisp crate
-    pub fn validate(aisp: &str) -> aisp::ValidationResult {
-        aisp::validate(aisp)
+        RosettaStone::document_to_prose(aisp)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Extract the recognized Rosetta symbols from an AISP document, in order
+    ///
+    /// Identifiers, whitespace, and block scaffolding (`⟦…⟧`, `⟨…⟩`) are
+    /// dropped, giving a canonical form for comparing specs regardless of
+    /// variable names.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// assert_eq!(AispConverter::symbol_stream("∀x∈S: x≥0"), vec!["∀", "∈", "≥"]);
+    /// ```
+    pub fn symbol_stream(aisp: &str) -> Vec<&'static str> {
+        const SCAFFOLDING: &[&str] = &["⟨", "⟩"];
+
+        RosettaStone::tokenize(aisp)
+            .iter()
+            .filter_map(|token| ROSETTA.iter().find(|e| e.symbol == token))
+            .filter(|e| e.category != "block" && !SCAFFOLDING.contains(&e.symbol))
+            .map(|e| e.symbol)
+            .collect()
+    }
+
+    /// Share of an AISP document's non-whitespace chars that are Rosetta symbols
+    ///
+    /// Unlike `ConversionResult::confidence`, this scores any document, not a
+    /// conversion, so it can flag under-converted third-party specs. Word-like
+    /// symbols (`Maybe`, `fix`) count as identifiers. Empty input scores 0.0.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// assert_eq!(AispConverter::symbolic_density("∀x∈S"), 0.5);
+    /// assert_eq!(AispConverter::symbolic_density("for all x in S"), 0.0);
+    /// ```
+    pub fn symbolic_density(aisp: &str) -> f64 {
+        let total = aisp.chars().filter(|c| !c.is_whitespace()).count();
+        if total == 0 {
+            return 0.0;
+        }
+
+        let symbolic: usize = RosettaStone::tokenize(aisp)
+            .iter()
+            .filter(|token| !token.chars().all(|c| c.is_ascii_alphanumeric()))
+            .filter(|token| ROSETTA.iter().any(|e| e.symbol == token.as_str()))
+            .map(|token| token.chars().count())
+            .sum();
+        symbolic as f64 / total as f64
+    }
+
+    /// Validate AISP document using the aisp crate
+    pub fn validate(aisp: &str) -> aisp::ValidationResult {
+        aisp::validate(aisp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_detect_tier_minimal() {
@@ -476,6 +2030,111 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_tier_with_policy() {
+        let mut policy = TierPolicy {
+            standard_word_count: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            AispConverter::detect_tier_with_policy("Define x as 5", policy.clone()),
+            ConversionTier::Standard
+        );
+
+        policy.proof_words.push("lemma".to_string());
+        policy.rule_words.clear();
+        assert_eq!(
+            AispConverter::detect_tier_with_policy("lemma", policy.clone()),
+            ConversionTier::Full
+        );
+        assert_eq!(
+            AispConverter::detect_tier_with_policy("x must", policy.clone()),
+            ConversionTier::Minimal
+        );
+
+        let result = AispConverter::convert(
+            "lemma",
+            Some(ConversionOptions {
+                tier_policy: Some(policy),
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result.tier, ConversionTier::Full);
+    }
+
+    #[test]
+    fn test_tier_requirements() {
+        let hints = AispConverter::tier_requirements("Define x as 5", ConversionTier::Standard);
+        assert!(hints.contains(&"add a rule such as \"must\"".to_string()));
+        assert!(hints.contains(&"write more than 20 words (currently 4)".to_string()));
+
+        let hints = AispConverter::tier_requirements(
+            "The user must provide valid authentication",
+            ConversionTier::Full,
+        );
+        assert!(hints.contains(&"ask for a proof such as \"prove\"".to_string()));
+        assert!(hints.contains(&"mention a type such as \"type\" alongside the rule".to_string()));
+
+        // Following a hint reaches the target
+        let prose = "The user must provide a valid token type";
+        assert_eq!(AispConverter::detect_tier(prose), ConversionTier::Full);
+        assert!(AispConverter::tier_requirements(prose, ConversionTier::Full).is_empty());
+    }
+
+    #[test]
+    fn test_infer_proof() {
+        assert_eq!(
+            AispConverter::infer_proof("A implies B, B implies C, therefore A implies C")
+                .as_deref(),
+            Some("π≜A⇒B∧B⇒C;⊢A⇒C")
+        );
+        // The conclusion is derived when unstated, and premises may come in any order
+        assert_eq!(
+            AispConverter::infer_proof("q implies r. p implies q.").as_deref(),
+            Some("π≜p⇒q∧q⇒r;⊢p⇒r")
+        );
+        // A conclusion the premises don't support proves nothing
+        assert_eq!(
+            AispConverter::infer_proof("A implies B, B implies C, therefore C implies A"),
+            None
+        );
+        assert_eq!(AispConverter::infer_proof("A implies B"), None);
+
+        let result = AispConverter::convert(
+            "A implies B, B implies C, therefore A implies C",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Full),
+                ..Default::default()
+            }),
+        );
+        assert!(result.output.contains("π≜A⇒B∧B⇒C;⊢A⇒C;∎⟩"));
+    }
+
+    #[test]
+    fn test_classify_tier_inverts_convert() {
+        for tier in [
+            ConversionTier::Minimal,
+            ConversionTier::Standard,
+            ConversionTier::Full,
+        ] {
+            let result = AispConverter::convert(
+                "Define x as 5",
+                Some(ConversionOptions {
+                    tier: Some(tier),
+                    ..Default::default()
+                }),
+            );
+            assert_eq!(AispConverter::classify_tier(&result.output), tier);
+        }
+
+        // Errors block without a proof marker is not Full
+        let partial = "⟦Ω:Meta⟧{}⟦Σ:Types⟧{}⟦Γ:Rules⟧{}⟦Λ:Funcs⟧{}⟦Χ:Errors⟧{}⟦Ε⟧⟨δ≜0.70⟩";
+        assert_eq!(
+            AispConverter::classify_tier(partial),
+            ConversionTier::Standard
+        );
+    }
+
     #[test]
     fn test_convert_minimal() {
         let result = AispConverter::convert("Define x as 5", None);
@@ -515,10 +2174,784 @@ mod tests {
         assert!(result.output.contains("⟦Χ:Errors⟧"));
     }
 
+    #[test]
+    fn test_convert_full_template() {
+        let template = FullTemplate::default()
+            .blocks(vec![
+                FullBlock::Meta,
+                FullBlock::Rules,
+                FullBlock::Types,
+                FullBlock::Funcs,
+            ])
+            .with_block(FullBlock::Custom {
+                header: "Ψ:Intents".to_string(),
+                body: "  goal≜ship".to_string(),
+            })
+            .delta(0.9)
+            .tau("◊⁺");
+        let result = AispConverter::convert(
+            "Define x as 5",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Full),
+                full_template: template,
+                ..Default::default()
+            }),
+        );
+
+        let output = &result.output;
+        assert!(!output.contains("⟦Χ:Errors⟧"));
+        assert!(output.find("⟦Γ:Rules⟧").unwrap() < output.find("⟦Σ:Types⟧").unwrap());
+        assert!(output.contains("⟦Ψ:Intents⟧{\n  goal≜ship\n}"));
+        assert!(output.ends_with("⟦Ε⟧⟨δ≜0.90;φ≜100;τ≜◊⁺;⊢valid;∎⟩"));
+    }
+
+    #[test]
+    fn test_standard_infers_types_and_rules() {
+        let standard = ConversionOptions {
+            tier: Some(ConversionTier::Standard),
+            date: Some(DateTime::UNIX_EPOCH),
+            ..Default::default()
+        };
+        let result = AispConverter::convert("Every user must have a name", Some(standard.clone()));
+        assert!(result
+            .output
+            .contains("⟦Σ:Types⟧{\n  𝕊≜strings\n  User≜⟨id:ℕ,name:𝕊⟩\n}"));
+        assert!(result
+            .output
+            .contains("⟦Γ:Rules⟧{\n  ∀x:T:require(x)⇒proceed(x)\n  ∀x∈S:P(x)\n}"));
+        assert_eq!(
+            AispConverter::classify_tier(&result.output),
+            ConversionTier::Standard
+        );
+
+        // Nothing inferred: no placeholders, unlike Full
+        let result = AispConverter::convert("Define x as 5", Some(standard));
+        assert!(result.output.contains("⟦Σ:Types⟧{\n  ∅\n}"));
+        assert!(result.output.contains("⟦Γ:Rules⟧{\n  ∅\n}"));
+    }
+
+    #[test]
+    fn test_omit_empty_blocks() {
+        let standard = ConversionOptions {
+            tier: Some(ConversionTier::Standard),
+            date: Some(DateTime::UNIX_EPOCH),
+            ..Default::default()
+        };
+        let padded = AispConverter::convert("Define x as 5", Some(standard.clone()));
+        let lean = AispConverter::convert(
+            "Define x as 5",
+            Some(ConversionOptions {
+                omit_empty_blocks: true,
+                ..standard
+            }),
+        );
+        assert!(padded.output.contains("⟦Σ:Types⟧"));
+        assert!(!lean.output.contains("⟦Σ:Types⟧"));
+        assert!(!lean.output.contains("⟦Γ:Rules⟧"));
+        assert!(lean.output.contains("⟦Ω:Meta⟧"));
+        assert!(lean.output.contains("⟦Λ:Funcs⟧"));
+        assert!(lean.output.ends_with("⟦Ε⟧⟨δ≜0.70;τ≜◊⁺⟩"));
+        assert!(lean.tokens.output < padded.tokens.output);
+
+        // Full drops an empty Errors block but keeps the populated ones
+        let full = AispConverter::convert(
+            "Define x as 5",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Full),
+                omit_empty_blocks: true,
+                ..Default::default()
+            }),
+        );
+        assert!(!full.output.contains("⟦Χ:Errors⟧"));
+        assert!(full.output.contains("⟦Σ:Types⟧"));
+        assert!(full.output.contains("⊢valid;∎⟩"));
+    }
+
+    #[test]
+    fn test_category_confidence() {
+        let result = AispConverter::convert("for all x in S, x or y", None);
+        assert!(result.category_confidence.contains_key("quantifier"));
+        assert!(result.category_confidence.contains_key("logic"));
+        assert!(!result.category_confidence.contains_key("type"));
+
+        let total: f64 = result.category_confidence.values().sum();
+        assert!((total - result.confidence).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lossless_mode_reports_skipped() {
+        let result = AispConverter::convert(
+            "for all x in S, f returns x",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Minimal),
+                mode: ConversionMode::Lossless,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result.output, "∀ x∈S, f returns x");
+        assert_eq!(
+            result.skipped_substitutions,
+            [("returns".to_string(), "→".to_string())]
+        );
+
+        let compact = AispConverter::convert("for all x in S, f returns x", None);
+        assert!(compact.skipped_substitutions.is_empty());
+    }
+
+    #[test]
+    fn test_confidence_weighted() {
+        // Short common words count for less than their characters
+        let incidental = AispConverter::convert("x in S or T", None);
+        assert!(incidental.confidence_weighted < incidental.confidence);
+
+        // A long specific phrase counts for more
+        let specific = AispConverter::convert("p if and only if q", None);
+        assert!(specific.confidence_weighted > specific.confidence);
+    }
+
+    #[test]
+    fn test_merge_results() {
+        let first = AispConverter::convert("for all x in S, foo", None);
+        let second = AispConverter::convert(
+            "Define y as bar",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Standard),
+                ..Default::default()
+            }),
+        );
+        let merged = ConversionResult::merge(&[first.clone(), second.clone()]);
+
+        assert_eq!(
+            merged.output,
+            format!("{}\n\n{}", first.output, second.output)
+        );
+        assert_eq!(
+            merged.tokens.input,
+            first.tokens.input + second.tokens.input
+        );
+        assert_eq!(merged.tokens.output, merged.output.len());
+        assert_eq!(merged.confidence, first.confidence.min(second.confidence));
+        assert_eq!(merged.tier, ConversionTier::Standard);
+        assert!(merged.unmapped.contains(&"foo".to_string()));
+        assert!(merged.unmapped.contains(&"bar".to_string()));
+
+        let empty = ConversionResult::merge(&[]);
+        assert_eq!(empty.output, "");
+        assert_eq!(empty.tier, ConversionTier::Minimal);
+    }
+
+    #[test]
+    fn test_convert_pinned_date() {
+        let date = DateTime::parse_from_rfc3339("2025-03-14T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let options = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            date: Some(date),
+            ..Default::default()
+        };
+
+        let first = AispConverter::convert("Define x as 5", Some(options.clone()));
+        let second = AispConverter::convert("Define x as 5", Some(options));
+        assert!(first.output.starts_with("𝔸5.1.domain@2025-03-14"));
+        assert_eq!(first.output, second.output);
+    }
+
+    #[test]
+    fn test_infer_rules_deduplicated_and_ordered() {
+        let prose =
+            "Every admin must check all input; all users require valid tokens before every change";
+        let rules = AispConverter::infer_rules(prose);
+        let lines: Vec<_> = rules.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "  ∀x:T:require(x)⇒proceed(x)",
+                "  ∀x:T:valid(x)⇒accept(x)",
+                "  ∀x∈S:P(x)",
+                "  ∀u∈User:u.admin≡⊤⇒allow(u)",
+                "  Pre(f)≜req(args)",
+                "  Δ(s)≜s'−s",
+            ]
+        );
+        assert_eq!(rules, AispConverter::infer_rules(prose));
+        assert_eq!(AispConverter::infer_rules("x"), "  ∀x:T:⊤");
+    }
+
+    #[test]
+    fn test_convert_singularize() {
+        let prose = "flags are booleans";
+        assert!(!AispConverter::convert(prose, None).output.contains("𝔹"));
+
+        let result = AispConverter::convert(
+            prose,
+            Some(ConversionOptions {
+                singularize: true,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result.output.matches('𝔹').count(), 2);
+    }
+
+    #[test]
+    fn test_convert_split_concatenated() {
+        let prose = "x greaterthan 5 ifandonlyif y notequal z";
+        let opts = ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            ..Default::default()
+        };
+        assert!(!AispConverter::convert(prose, Some(opts.clone()))
+            .output
+            .contains('⇔'));
+
+        let result = AispConverter::convert(
+            prose,
+            Some(ConversionOptions {
+                split_concatenated: true,
+                ..opts
+            }),
+        );
+        assert_eq!(result.output.replace(' ', ""), "x>5⇔y≢z");
+        assert!(result.unmapped.is_empty());
+    }
+
+    #[test]
+    fn test_convert_normalize_identifiers() {
+        let result = AispConverter::convert(
+            "response time must be under 200ms",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Minimal),
+                normalize_identifiers: true,
+                ..Default::default()
+            }),
+        );
+        assert_eq!(result.output, "response_time<200ms");
+    }
+
+    #[test]
+    fn test_convert_clauses() {
+        let prose = "Define x as 5. For all y in S, y is at least 0.5, e.g. y is 1! Done";
+        let opts = ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            ..Default::default()
+        };
+        let result = AispConverter::convert(
+            prose,
+            Some(ConversionOptions {
+                clause_separator: Some(" ∧ ".to_string()),
+                ..opts.clone()
+            }),
+        );
+        assert_eq!(result.output, "x≜5 ∧ ∀ y∈S, y≥0.5, e.g. y is 1 ∧ ∎");
+        assert_eq!(
+            result.confidence,
+            AispConverter::convert(prose, Some(opts)).confidence
+        );
+
+        // Full-tier Funcs bodies keep one clause per line
+        let result = AispConverter::convert(
+            "Define x as 5. Define y as 6.",
+            Some(ConversionOptions {
+                tier: Some(ConversionTier::Full),
+                clause_separator: Some("\n".to_string()),
+                ..Default::default()
+            }),
+        );
+        assert!(result.output.contains("⟦Λ:Funcs⟧{\n  x≜5\n  y≜6\n}"));
+    }
+
+    #[test]
+    fn test_annotate_source() {
+        let prose = "Define x as 5. For all y in S, y is \"valid\".";
+        let plain = AispConverter::convert(prose, None);
+        let annotated = AispConverter::convert(
+            prose,
+            Some(ConversionOptions {
+                annotate_source: true,
+                ..Default::default()
+            }),
+        );
+
+        assert!(annotated
+            .output
+            .contains("x≜5. ⟨prose:\"Define x as 5.\"⟩\n"));
+        assert!(annotated
+            .output
+            .contains(r#"⟨prose:"For all y in S, y is \"valid\"."⟩"#));
+        assert_eq!(annotated.confidence, plain.confidence);
+        assert_eq!(
+            RosettaStone::to_prose(&annotated.output),
+            RosettaStone::to_prose(&plain.output)
+        );
+    }
+
     #[test]
     fn test_to_prose() {
         let prose = AispConverter::to_prose("∀x∈S");
         assert!(prose.contains("for all"));
         assert!(prose.contains("in"));
     }
+
+    #[test]
+    fn test_convert_is_idempotent() {
+        let inputs = [
+            "for all x in S, x is greater than 0",
+            "Define x as 5",
+            "if x then y else z",
+            "x is at least 5 and at most 10",
+            "The user must authenticate to access the API",
+            "Define type User with id and name. All users must have valid email. Verify that each user exists.",
+            "for each item in list, validate it",
+        ];
+        let minimal = ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            ..Default::default()
+        };
+
+        for input in inputs {
+            let once = AispConverter::convert(input, None);
+            let twice = AispConverter::convert(&once.output, None);
+            assert_eq!(twice.output, once.output, "not idempotent for {:?}", input);
+            assert_eq!(twice.tier, once.tier);
+
+            // The bare symbols convert back to themselves too ("∀item∈list:" keeps its set)
+            let once = AispConverter::convert(input, Some(minimal.clone()));
+            let twice = AispConverter::convert(&once.output, Some(minimal.clone()));
+            assert_eq!(twice.output, once.output, "not idempotent for {:?}", input);
+        }
+
+        // A higher requested tier still wraps minimal AISP
+        let opts = ConversionOptions {
+            tier: Some(ConversionTier::Standard),
+            ..Default::default()
+        };
+        let wrapped = AispConverter::convert("∀x∈S", Some(opts));
+        assert_eq!(wrapped.tier, ConversionTier::Standard);
+        assert!(wrapped.output.contains("∀x∈S"));
+    }
+
+    #[test]
+    fn test_convert_empty_input() {
+        for input in ["", "   ", "\n\n"] {
+            for tier in [
+                None,
+                Some(ConversionTier::Minimal),
+                Some(ConversionTier::Standard),
+                Some(ConversionTier::Full),
+            ] {
+                let opts = ConversionOptions {
+                    tier,
+                    ..Default::default()
+                };
+                let result = AispConverter::convert(input, Some(opts));
+                assert_eq!(result.output, "", "input {:?}, tier {:?}", input, tier);
+                assert_eq!(result.confidence, 1.0);
+                assert_eq!(result.tier, ConversionTier::Minimal);
+                assert!(result.unmapped.is_empty());
+                assert!(!result.below_threshold);
+            }
+        }
+    }
+
+    #[test]
+    fn test_confidence_threshold() {
+        let result = AispConverter::convert("Lorem ipsum dolor sit amet", None);
+        assert!(result.below_threshold);
+
+        let lenient = ConversionOptions {
+            confidence_threshold: Some(0.0),
+            ..Default::default()
+        };
+        let result = AispConverter::convert("Lorem ipsum dolor sit amet", Some(lenient.clone()));
+        assert!(!result.below_threshold);
+        assert!(AispConverter::try_convert("Lorem ipsum dolor sit amet", Some(lenient)).is_ok());
+
+        let err = AispConverter::try_convert("Lorem ipsum dolor sit amet", None).unwrap_err();
+        let ConversionError::BelowThreshold {
+            confidence,
+            threshold,
+        } = err.clone()
+        else {
+            panic!("expected BelowThreshold, got {:?}", err);
+        };
+        assert_eq!(threshold, 0.8);
+        assert!(confidence < threshold);
+        assert!(err.to_string().ends_with("is below threshold 0.80"));
+
+        // Already-converted input is fully confident
+        assert!(!AispConverter::convert("∀x∈S", None).below_threshold);
+    }
+
+    #[test]
+    fn test_passthrough_below_threshold() {
+        let prose = "Lorem ipsum dolor and sit amet";
+        let result = AispConverter::convert_or_passthrough(prose, 0.8);
+        assert!(result.passthrough);
+        assert!(result.below_threshold);
+        assert_eq!(result.output, prose);
+        assert!(result.confidence < 0.8);
+        assert!(!result.unmapped.is_empty());
+        assert_eq!(result.tokens.ratio, 1.0);
+
+        let result = AispConverter::convert_or_passthrough(prose, 0.0);
+        assert!(!result.passthrough);
+        assert!(result.output.contains('∧'));
+
+        // Without the option, low confidence is only flagged
+        let result = AispConverter::convert(prose, None);
+        assert!(result.below_threshold);
+        assert!(!result.passthrough);
+
+        // Every tier passes through the same way
+        let full = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            passthrough_below_threshold: true,
+            ..Default::default()
+        };
+        let result = AispConverter::convert(prose, Some(full));
+        assert!(result.passthrough);
+        assert_eq!(result.output, prose);
+    }
+
+    #[test]
+    fn test_normalize_numerals_option() {
+        let prose = "three users and the first admin or twenty-one guests";
+        let result = AispConverter::convert(prose, None);
+        assert!(result.unmapped.contains(&"three".to_string()));
+
+        let options = ConversionOptions {
+            normalize_numerals: true,
+            ..Default::default()
+        };
+        let result = AispConverter::convert(prose, Some(options));
+        assert_eq!(result.output, "3 users∧the 1st admin∨21 guests");
+        assert!(!result.unmapped.contains(&"three".to_string()));
+        assert!(!result.unmapped.contains(&"first".to_string()));
+    }
+
+    #[test]
+    fn test_line_as_clause() {
+        let requirements = "- x is true and\n- y is false\n\n  - z is greater\nthan 5";
+        let options = ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            ..Default::default()
+        };
+        // Joined, cleanup pulls the next requirement onto the trailing "and"
+        let joined = AispConverter::convert(requirements, Some(options.clone())).output;
+        assert!(joined.starts_with("- x is ⊤∧- y is ⊥"), "{:?}", joined);
+
+        let options = ConversionOptions {
+            line_as_clause: true,
+            ..options
+        };
+        let result = AispConverter::convert(requirements, Some(options.clone()));
+        assert_eq!(
+            result.output,
+            "- x is ⊤∧\n- y is ⊥\n\n  - z is greater\nthan 5"
+        );
+        assert_eq!(result.pattern_counts.get("and"), Some(&1));
+
+        // Sentences split within each line
+        let options = ConversionOptions {
+            clause_separator: Some(" ; ".to_string()),
+            ..options
+        };
+        let result = AispConverter::convert("x is true. y is false\nz is true", Some(options));
+        assert_eq!(result.output, "x is ⊤ ; y is ⊥\nz is ⊤");
+    }
+
+    #[test]
+    fn test_quality_score() {
+        let score = AispConverter::quality_score("x is true and y is false");
+        assert!(score.forward_confidence > 0.0);
+        assert!((score.roundtrip_fidelity - 1.0).abs() < 1e-9);
+        assert_eq!(
+            score.combined,
+            QualityScore::CONFIDENCE_WEIGHT * score.forward_confidence
+                + QualityScore::FIDELITY_WEIGHT * score.roundtrip_fidelity
+        );
+
+        // "element of" comes back as "in": mapped, but not reversible word for word
+        let lossy = AispConverter::quality_score("x is an element of S");
+        assert!(lossy.roundtrip_fidelity < 1.0);
+        assert!(lossy.combined < score.combined);
+    }
+
+    #[test]
+    fn test_convert_budgeted() {
+        let prose = "for all x in S, x is greater than 0 and y is less than 1";
+        assert_eq!(RosettaStone::tokenize(prose).len(), 17);
+
+        // Already within budget: nothing is substituted
+        let result = AispConverter::convert_budgeted(prose, 17);
+        assert_eq!(result.output, prose);
+        assert!(result.applied.is_empty());
+
+        // Equal savings go to the earlier phrase
+        let result = AispConverter::convert_budgeted(prose, 16);
+        assert_eq!(
+            result.output,
+            "∀ x in S, x is greater than 0 and y is less than 1"
+        );
+        assert_eq!(result.applied[0].span, 0..7);
+        assert_eq!(result.applied[0].symbol, "∀");
+
+        // An unreachable budget applies every saving substitution, and only those
+        let result = AispConverter::convert_budgeted(prose, 0);
+        assert_eq!(result.output, "∀ x in S, x is > 0 and y is < 1");
+        assert_eq!(result.tokens, 14);
+        let symbols: Vec<_> = result.applied.iter().map(|p| p.symbol).collect();
+        assert_eq!(symbols, ["∀", ">", "<"]);
+
+        // Escaped spans stay as written
+        let result = AispConverter::convert_budgeted(r"\aisp{for all x} and y greater than z", 0);
+        assert_eq!(result.output, r"\aisp{for all x} and y > z");
+
+        // Hints apply whole, even within budget
+        let result = AispConverter::convert_budgeted("x [[⊆:is contained in]] T", 100);
+        assert_eq!(result.output, "x ⊆ T");
+        assert_eq!(result.applied.len(), 1);
+    }
+
+    #[test]
+    fn test_preserve_symbols() {
+        let mixed = "∀x∈S:x>0∧x<9 and y is true";
+        let options = ConversionOptions {
+            preserve_symbols: true,
+            ..Default::default()
+        };
+
+        // Symbol-heavy enough to read as AISP, so by default it is returned as-is
+        let result = AispConverter::convert(mixed, None);
+        assert_eq!(result.output, mixed);
+        assert!(result.symbol_share > 0.3);
+
+        let result = AispConverter::convert(mixed, Some(options.clone()));
+        assert_eq!(result.output, "∀x∈S:x>0∧x<9∧y is ⊤");
+        assert_eq!(result.tier, ConversionTier::Minimal);
+        assert!((result.symbol_share - 5.0 / 16.0).abs() < 1e-9);
+
+        // Converting the output again changes nothing
+        for prose in [mixed, "∀ users must authenticate", "x≔5 and y≜3 or z"] {
+            let once = AispConverter::convert(prose, Some(options.clone())).output;
+            let twice = AispConverter::convert(&once, Some(options.clone())).output;
+            assert_eq!(once, twice, "{}", prose);
+        }
+
+        assert_eq!(AispConverter::convert("x and y", None).symbol_share, 0.0);
+    }
+
+    #[test]
+    fn test_generated_documents_validate() {
+        let inputs = [
+            "Define x as 5",
+            "for all x in S, x is greater than 0",
+            "Define type User with id and name. All users must have valid email.",
+            // No type keywords, so Full falls back to a placeholder type
+            "x must be at least 5",
+        ];
+        for input in inputs {
+            for tier in [ConversionTier::Standard, ConversionTier::Full] {
+                let opts = ConversionOptions {
+                    tier: Some(tier),
+                    ..Default::default()
+                };
+                let result = AispConverter::convert_validated(input, Some(opts));
+                assert!(result.is_ok(), "{:?} at {:?}: {:?}", input, tier, result);
+            }
+        }
+    }
+
+    #[test]
+    fn test_convert_checked() {
+        // Minimal output is validated too, inside a document of its own
+        let result = AispConverter::convert_checked("x is at least 5", None);
+        assert_eq!(result.unwrap().tier, ConversionTier::Minimal);
+
+        let opts = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            full_template: FullTemplate::default().without_block(FullBlock::Funcs),
+            ..Default::default()
+        };
+        let Err(err) = AispConverter::convert_checked("Define x as 5", Some(opts.clone())) else {
+            panic!("a document without ⟦Λ:Funcs⟧ validated");
+        };
+        let ValidationError::Invalid { block, report } = &err;
+        assert!(!report.valid);
+        assert_eq!(report.error, Some("Missing required blocks"));
+        assert!(err.to_string().ends_with(": Missing required blocks"), "{}", err);
+
+        // convert_validated reports the same failure as a ConversionError
+        assert_eq!(
+            AispConverter::convert_validated("Define x as 5", Some(opts)).unwrap_err(),
+            ConversionError::Invalid {
+                block: block.clone(),
+                details: "Missing required blocks".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_failing_block() {
+        // Without the 𝔸 header no block validates, so the first is reported
+        let doc = "⟦Ω:Meta⟧{\n  x\n}\n\n⟦Σ:Types⟧{\n  ∅\n}";
+        assert_eq!(
+            AispConverter::failing_block(doc),
+            Some("⟦Ω:Meta⟧".to_string())
+        );
+        assert_eq!(AispConverter::failing_block("no blocks"), None);
+
+        let err = ConversionError::Invalid {
+            block: Some("⟦Σ:Types⟧".to_string()),
+            details: "bad".to_string(),
+        };
+        assert_eq!(
+            err.to_string(),
+            "output failed AISP validation in ⟦Σ:Types⟧: bad"
+        );
+    }
+
+    #[test]
+    fn test_token_stats_compression() {
+        let stats = TokenStats::measure(&"a".repeat(200), &"b".repeat(110));
+        assert_eq!(stats.ratio, 0.55);
+        assert_eq!(stats.compression_ratio, 1.82);
+        assert_eq!(stats.savings_percent, 45.0);
+
+        // Expansion reports negative savings
+        let stats = TokenStats::measure(&"a".repeat(100), &"b".repeat(250));
+        assert_eq!(stats.savings_percent, -150.0);
+
+        let stats = TokenStats::measure("", "");
+        assert_eq!(stats.compression_ratio, 0.0);
+        assert_eq!(stats.savings_percent, 0.0);
+    }
+
+    #[test]
+    fn test_token_stats_chars() {
+        let stats = TokenStats::measure("for all x in S", "∀x∈S");
+        assert_eq!(stats.output, 8);
+        assert_eq!(stats.output_size.bytes, 8);
+        assert_eq!(stats.output_size.chars, 4);
+        assert_eq!(stats.input_size.chars, 14);
+
+        #[cfg(feature = "graphemes")]
+        assert_eq!(TextSize::of("e\u{301}∀").graphemes, Some(2));
+        #[cfg(not(feature = "graphemes"))]
+        assert_eq!(stats.output_size.graphemes, None);
+    }
+
+    #[test]
+    fn test_symbolic_density() {
+        assert_eq!(AispConverter::symbolic_density(""), 0.0);
+        assert_eq!(AispConverter::symbolic_density("  \n"), 0.0);
+        assert_eq!(AispConverter::symbolic_density("∀ ∃!"), 1.0);
+        // Word-like symbols are not counted
+        assert_eq!(AispConverter::symbolic_density("Maybe"), 0.0);
+
+        let prose = "All users must have a valid email";
+        let converted = AispConverter::convert(prose, None);
+        assert!(
+            AispConverter::symbolic_density(&converted.output)
+                > AispConverter::symbolic_density(prose)
+        );
+    }
+
+    #[test]
+    fn test_symbol_stream() {
+        // Identifiers do not affect the stream
+        assert_eq!(
+            AispConverter::symbol_stream("∀x∈S"),
+            AispConverter::symbol_stream("∀ item ∈ Items")
+        );
+        // Multi-character symbols are kept whole
+        assert_eq!(AispConverter::symbol_stream("x∃!y ◊⁺⁺"), vec!["∃!", "◊⁺⁺"]);
+
+        let opts = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            ..Default::default()
+        };
+        let result = AispConverter::convert("for all x in S", Some(opts));
+        let stream = AispConverter::symbol_stream(&result.output);
+        assert!(stream.contains(&"∀"));
+        assert!(!stream.contains(&"⟦Ε⟧"));
+        assert!(!stream.contains(&"⟨"));
+    }
+
+    #[test]
+    fn test_parenthesis_warnings() {
+        let opts = ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            ..Default::default()
+        };
+        let result = AispConverter::convert("(a or b) and c", Some(opts.clone()));
+        assert_eq!(result.output, "(a∨b)∧c");
+        assert!(result.warnings.is_empty());
+
+        let result = AispConverter::convert("a or b) and (c", Some(opts));
+        assert_eq!(result.output, "a∨b)∧(c");
+        assert_eq!(
+            result.warnings,
+            vec![
+                "unbalanced parentheses: unmatched ')' at byte 6",
+                "unbalanced parentheses: unmatched '(' at byte 12",
+            ]
+        );
+
+        // Higher tiers carry the warnings of their minimal pass
+        let full = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            ..Default::default()
+        };
+        let result = AispConverter::convert("x must be (positive", Some(full));
+        assert_eq!(result.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_plan() {
+        let (tier, reasons) = AispConverter::plan("Define x as 5");
+        assert_eq!(tier, ConversionTier::Minimal);
+        assert_eq!(reasons, vec!["4 words with no tier triggers"]);
+
+        let prose = "Prove that every user type must be valid";
+        let (tier, reasons) = AispConverter::plan(prose);
+        assert_eq!(tier, AispConverter::detect_tier(prose));
+        assert_eq!(tier, ConversionTier::Full);
+        assert!(reasons.contains(&"proof word \"Prove\"".to_string()));
+        assert!(reasons.iter().any(|r| r.contains(" with rule word ")));
+
+        // The plan matches the tier convert reports
+        for prose in [
+            "",
+            "x and y",
+            "The API must return a string type",
+            "∀x∈S:x≥0",
+        ] {
+            let (tier, reasons) = AispConverter::plan(prose);
+            assert_eq!(
+                tier,
+                AispConverter::convert(prose, None).tier,
+                "{:?}",
+                prose
+            );
+            assert!(!reasons.is_empty());
+        }
+
+        let forced = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            ..Default::default()
+        };
+        assert_eq!(
+            AispConverter::plan_with_options("x and y", Some(forced)),
+            (
+                ConversionTier::Full,
+                vec!["full tier requested in options".to_string()]
+            )
+        );
+        assert_eq!(
+            AispConverter::plan("∀x∈S:x≥0").1,
+            vec!["already AISP at the minimal tier"]
+        );
+    }
 }
@@ -0,0 +1,162 @@
+//! Symbol-level diff between AISP documents
+//!
+//! Compares token streams (symbols and identifiers) rather than characters,
+//! so formatting differences don't show up as changes.
+
+use crate::converter::AispConverter;
+use crate::rosetta::RosettaStone;
+use serde::{Deserialize, Serialize};
+
+/// A difference between two AISP token streams
+///
+/// Positions are token indices: `Removed` and `Changed` index into the first
+/// document, `Added` into the second.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum SymbolDiff {
+    Added {
+        token: String,
+        position: usize,
+    },
+    Removed {
+        token: String,
+        position: usize,
+    },
+    Changed {
+        from: String,
+        to: String,
+        position: usize,
+    },
+}
+
+impl AispConverter {
+    /// Diff two AISP documents at the symbol level
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, SymbolDiff};
+    ///
+    /// let diff = AispConverter::diff("∀x∈S", "∃x ∈ S");
+    /// assert_eq!(diff, vec![SymbolDiff::Changed {
+    ///     from: "∀".to_string(),
+    ///     to: "∃".to_string(),
+    ///     position: 0,
+    /// }]);
+    /// ```
+    pub fn diff(a: &str, b: &str) -> Vec<SymbolDiff> {
+        let a = RosettaStone::tokenize(a);
+        let b = RosettaStone::tokenize(b);
+
+        // Longest common subsequence table over the token streams
+        let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                lcs[i][j] = if a[i] == b[j] {
+                    lcs[i + 1][j + 1] + 1
+                } else {
+                    lcs[i + 1][j].max(lcs[i][j + 1])
+                };
+            }
+        }
+
+        let mut diffs = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() || j < b.len() {
+            if i < a.len() && j < b.len() && a[i] == b[j] {
+                i += 1;
+                j += 1;
+                continue;
+            }
+
+            // Collect the run of removals and additions up to the next common token
+            let (start_i, start_j) = (i, j);
+            while i < a.len() || j < b.len() {
+                if i < a.len() && j < b.len() && a[i] == b[j] {
+                    break;
+                }
+                if j == b.len() || (i < a.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+                    i += 1;
+                } else {
+                    j += 1;
+                }
+            }
+
+            // Pair removals with additions as changes; leftovers stay one-sided
+            let removed = &a[start_i..i];
+            let added = &b[start_j..j];
+            let paired = removed.len().min(added.len());
+            for k in 0..paired {
+                diffs.push(SymbolDiff::Changed {
+                    from: removed[k].clone(),
+                    to: added[k].clone(),
+                    position: start_i + k,
+                });
+            }
+            for (k, token) in removed.iter().enumerate().skip(paired) {
+                diffs.push(SymbolDiff::Removed {
+                    token: token.clone(),
+                    position: start_i + k,
+                });
+            }
+            for (k, token) in added.iter().enumerate().skip(paired) {
+                diffs.push(SymbolDiff::Added {
+                    token: token.clone(),
+                    position: start_j + k,
+                });
+            }
+        }
+
+        diffs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_identical_ignores_whitespace() {
+        assert!(AispConverter::diff("∀x∈S:P(x)", "∀x ∈ S : P(x)").is_empty());
+    }
+
+    #[test]
+    fn test_diff_added_removed_changed() {
+        let diff = AispConverter::diff("x≜5∧y≜10", "x≔5∧y≜10∧z≜1");
+        assert_eq!(
+            diff,
+            vec![
+                SymbolDiff::Changed {
+                    from: "≜".to_string(),
+                    to: "≔".to_string(),
+                    position: 1,
+                },
+                SymbolDiff::Added {
+                    token: "∧".to_string(),
+                    position: 7,
+                },
+                SymbolDiff::Added {
+                    token: "z".to_string(),
+                    position: 8,
+                },
+                SymbolDiff::Added {
+                    token: "≜".to_string(),
+                    position: 9,
+                },
+                SymbolDiff::Added {
+                    token: "1".to_string(),
+                    position: 10,
+                },
+            ]
+        );
+
+        let diff = AispConverter::diff("¬∀x", "∀x");
+        assert_eq!(
+            diff,
+            vec![SymbolDiff::Changed {
+                from: "¬∀".to_string(),
+                to: "∀".to_string(),
+                position: 0,
+            }]
+        );
+    }
+}
@@ -0,0 +1,356 @@
+//! Match engines - the pattern-matching strategy behind symbol substitution
+//!
+//! `RosettaStone` runs its phrase passes around a single substitution step; a
+//! `MatchEngine` performs that step, so tables can be matched with something
+//! other than regexes (a trie, an FST) without changing the API.
+
+use crate::rosetta::{
+    compile_entries, match_order, sort_entries, CompiledRosettaEntry, EntryError, RosettaEntry,
+    RosettaStone, LOSSLESS_COMPILED, LOSSLESS_TABLE, ROSETTA, ROSETTA_COMPILED,
+};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A table compiled once for regex matching
+///
+/// `CompiledTable::builtin()` is the built-in `ROSETTA` table; build one from
+/// any other table with `try_new` (or `new`) and reuse it across conversions instead of
+/// recompiling per call.
+///
+/// As a `MatchEngine` it uses its compiled entries only when the pipeline
+/// passes the table it was compiled from (`table()`); any other table is
+/// matched as `RegexEngine` would. `RosettaStone::convert_with_table` passes
+/// it its own table.
+#[derive(Debug)]
+pub struct CompiledTable {
+    table: Vec<RosettaEntry>,
+    entries: Vec<CompiledRosettaEntry>,
+}
+
+lazy_static! {
+    /// `'static` tables compiled by `CompiledTable::cached`, by address and length
+    static ref CACHED_TABLES: Mutex<HashMap<(usize, usize), &'static CompiledTable>> =
+        Mutex::new(HashMap::new());
+}
+
+impl CompiledTable {
+    /// Compile a table, longest pattern first, rejecting it if any entry fails
+    /// `RosettaStone::validate_entry` or shadows another entry's pattern
+    /// (`RosettaStone::validate_table`)
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{CompiledTable, EntryError, RosettaEntry};
+    ///
+    /// static TABLE: &[RosettaEntry] = &[RosettaEntry {
+    ///     symbol: "$0",
+    ///     patterns: &["dollar"],
+    ///     category: "custom",
+    /// }];
+    /// assert!(matches!(
+    ///     CompiledTable::try_new(TABLE),
+    ///     Err(EntryError::DollarInSymbol { .. })
+    /// ));
+    /// ```
+    pub fn try_new(table: &[RosettaEntry]) -> Result<Self, EntryError> {
+        RosettaStone::validate_table(table)?;
+        Ok(Self::new(table))
+    }
+
+    /// Compile a table, longest pattern first, leaving out entries that fail
+    /// `RosettaStone::validate_entry`
+    ///
+    /// Invalid entries are dropped silently and shadowed patterns are not
+    /// checked; use `try_new` for a custom table to have both reported.
+    pub fn new(table: &[RosettaEntry]) -> Self {
+        let entries: Vec<_> = sort_entries(table)
+            .into_iter()
+            .filter(|entry| RosettaStone::validate_entry(entry).is_ok())
+            .collect();
+        Self {
+            table: table.to_vec(),
+            entries: compile_entries(&entries),
+        }
+    }
+
+    /// The built-in table, compiled on first use
+    pub fn builtin() -> &'static CompiledTable {
+        &ROSETTA_COMPILED
+    }
+
+    /// A `'static` table (a language pack), compiled on first use and kept
+    /// for the life of the process
+    pub fn cached(table: &'static [RosettaEntry]) -> &'static CompiledTable {
+        if std::ptr::eq(table, ROSETTA) {
+            return Self::builtin();
+        }
+        let key = (table.as_ptr() as usize, table.len());
+        let mut cache = CACHED_TABLES.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .entry(key)
+            .or_insert_with(|| Box::leak(Box::new(Self::new(table))))
+    }
+
+    /// The table this was compiled from, in its original order
+    pub fn table(&self) -> &[RosettaEntry] {
+        &self.table
+    }
+
+    /// Compiled entries in match order (longest pattern first)
+    pub fn entries(&self) -> &[CompiledRosettaEntry] {
+        &self.entries
+    }
+}
+
+impl MatchEngine for CompiledTable {
+    fn convert(&self, input: &str, table: &[RosettaEntry]) -> (String, Vec<Substitution>) {
+        if std::ptr::eq(table, self.table()) {
+            return apply_compiled(input, &self.entries);
+        }
+        RegexEngine.convert(input, table)
+    }
+}
+
+/// A single phrase replaced by a symbol
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution {
+    /// Text that matched, as written in the input
+    pub matched: String,
+    /// Rosetta pattern that matched (the symbol itself for an inline hint)
+    pub pattern: &'static str,
+    /// Symbol that replaced the phrase
+    pub symbol: &'static str,
+    /// Category of the matched entry
+    pub category: &'static str,
+}
+
+/// Strategy for replacing table patterns with their symbols
+pub trait MatchEngine {
+    /// Replace every pattern match in `input` with its entry's symbol
+    /// Returns (converted_text, substitutions applied)
+    fn convert(&self, input: &str, table: &[RosettaEntry]) -> (String, Vec<Substitution>);
+}
+
+/// Word-bounded, case-insensitive regex matching, longest pattern first (the default)
+///
+/// Operator patterns (">=", "!=") are matched before word patterns.
+///
+/// The built-in table and the `ConversionMode::Lossless` table are compiled
+/// once; other tables are compiled on each call with `CompiledTable::new`,
+/// leaving out entries that fail `RosettaStone::validate_entry`. Pass a
+/// `CompiledTable` as the engine to reuse one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RegexEngine;
+
+impl MatchEngine for RegexEngine {
+    fn convert(&self, input: &str, table: &[RosettaEntry]) -> (String, Vec<Substitution>) {
+        if std::ptr::eq(table, ROSETTA) {
+            return apply_compiled(input, CompiledTable::builtin().entries());
+        }
+        if std::ptr::eq(table, LOSSLESS_TABLE.as_slice()) {
+            return apply_compiled(input, LOSSLESS_COMPILED.entries());
+        }
+        apply_compiled(input, CompiledTable::new(table).entries())
+    }
+}
+
+/// Apply compiled entries in order, recording each match
+pub(crate) fn apply_compiled(
+    input: &str,
+    entries: &[CompiledRosettaEntry],
+) -> (String, Vec<Substitution>) {
+    let mut result = input.to_string();
+    let mut substitutions = Vec::new();
+
+    for (entry, i) in match_order(entries) {
+        let regex = &entry.regexes[i];
+        let matches: Vec<_> = regex.find_iter(&result).collect();
+        if matches.is_empty() {
+            continue;
+        }
+        substitutions.extend(matches.iter().map(|m| Substitution {
+            matched: m.as_str().to_string(),
+            pattern: entry.patterns[i],
+            symbol: entry.symbol,
+            category: entry.category,
+        }));
+        result = regex.replace_all(&result, entry.symbol).to_string();
+    }
+
+    (result, substitutions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Exact, case-sensitive substring replacement in table order
+    struct LiteralEngine;
+
+    impl MatchEngine for LiteralEngine {
+        fn convert(&self, input: &str, table: &[RosettaEntry]) -> (String, Vec<Substitution>) {
+            let mut result = input.to_string();
+            let mut substitutions = Vec::new();
+            for entry in table {
+                for pattern in entry.patterns {
+                    if result.contains(pattern) {
+                        substitutions.push(Substitution {
+                            matched: pattern.to_string(),
+                            pattern,
+                            symbol: entry.symbol,
+                            category: entry.category,
+                        });
+                        result = result.replace(pattern, entry.symbol);
+                    }
+                }
+            }
+            (result, substitutions)
+        }
+    }
+
+    #[test]
+    fn test_regex_engine_records_substitutions() {
+        let (output, subs) = RegexEngine.convert("For all x in S", ROSETTA);
+        assert_eq!(output, "∀ x ∈ S");
+        assert!(subs
+            .iter()
+            .any(|s| s.matched == "For all" && s.pattern == "for all" && s.symbol == "∀"));
+        assert!(subs.iter().any(|s| s.symbol == "∈" && s.category == "set"));
+    }
+
+    #[test]
+    fn test_regex_engine_matches_default_convert() {
+        let input = "for all x in S, x is greater than 0 and y is true";
+        let (with_engine, _, _) = RosettaStone::convert_with_engine(input, &RegexEngine);
+        assert_eq!(with_engine, RosettaStone::convert(input).0);
+    }
+
+    #[test]
+    fn test_regex_engine_skips_invalid_entries() {
+        static TABLE: &[RosettaEntry] = &[
+            RosettaEntry {
+                symbol: "$0",
+                patterns: &["dollar"],
+                category: "custom",
+            },
+            RosettaEntry {
+                symbol: "∧",
+                patterns: &["and"],
+                category: "logic",
+            },
+        ];
+        let (output, subs) = RegexEngine.convert("dollar and cents", TABLE);
+        assert_eq!(output, "dollar ∧ cents");
+        assert_eq!(subs.len(), 1);
+    }
+
+    #[test]
+    fn test_compiled_table() {
+        static TABLE: &[RosettaEntry] = &[
+            RosettaEntry {
+                symbol: "$0",
+                patterns: &["dollar"],
+                category: "custom",
+            },
+            RosettaEntry {
+                symbol: "⊕",
+                patterns: &["xor", "exclusive or"],
+                category: "logic",
+            },
+        ];
+        let table = CompiledTable::new(TABLE);
+        assert_eq!(table.entries().len(), 1);
+        assert_eq!(table.entries()[0].patterns, vec!["exclusive or", "xor"]);
+
+        let (output, mapped, _) =
+            RosettaStone::convert_with_table("a xor b or dollar", Some(&table));
+        assert_eq!(output, "a ⊕ b or dollar");
+        assert_eq!(mapped, 3);
+
+        // The built-in compiled table is what the default engine uses
+        assert!(std::ptr::eq(CompiledTable::builtin(), &*ROSETTA_COMPILED));
+        assert_eq!(
+            CompiledTable::builtin().convert("x and y", ROSETTA),
+            RegexEngine.convert("x and y", ROSETTA)
+        );
+
+        // Given another table, a compiled table matches that table instead
+        assert_eq!(table.convert("a xor b", table.table()).0, "a ⊕ b");
+        assert_eq!(
+            table.convert("x and y", ROSETTA),
+            RegexEngine.convert("x and y", ROSETTA)
+        );
+        assert_eq!(table.convert("x and y", table.table()).0, "x and y");
+    }
+
+    #[test]
+    fn test_try_new_validates_table() {
+        static INVALID: &[RosettaEntry] = &[
+            RosettaEntry {
+                symbol: "⊕",
+                patterns: &["xor"],
+                category: "logic",
+            },
+            RosettaEntry {
+                symbol: "$0",
+                patterns: &["dollar"],
+                category: "custom",
+            },
+        ];
+        assert_eq!(
+            CompiledTable::try_new(INVALID).unwrap_err(),
+            EntryError::DollarInSymbol {
+                symbol: "$0".to_string()
+            }
+        );
+
+        static SHADOWED: &[RosettaEntry] = &[
+            RosettaEntry {
+                symbol: "∧",
+                patterns: &["and", "together with"],
+                category: "logic",
+            },
+            RosettaEntry {
+                symbol: "↔",
+                patterns: &["to and fro"],
+                category: "logic",
+            },
+        ];
+        assert!(matches!(
+            CompiledTable::try_new(SHADOWED),
+            Err(EntryError::ShadowedPattern { .. })
+        ));
+
+        let table = CompiledTable::try_new(&INVALID[..1]).unwrap();
+        assert_eq!(table.entries().len(), 1);
+        assert_eq!(
+            RosettaStone::convert_with_table("a xor b", Some(&table)).0,
+            "a ⊕ b"
+        );
+    }
+
+    #[test]
+    fn test_cached_table() {
+        let spanish = CompiledTable::cached(crate::lang::SPANISH);
+        assert!(std::ptr::eq(
+            spanish,
+            CompiledTable::cached(crate::lang::SPANISH)
+        ));
+        assert!(std::ptr::eq(
+            CompiledTable::cached(ROSETTA),
+            CompiledTable::builtin()
+        ));
+    }
+
+    #[test]
+    fn test_custom_engine() {
+        let (output, mapped, _) = RosettaStone::convert_with_engine("x and y", &LiteralEngine);
+        assert!(output.contains('∧'));
+        assert_eq!(mapped, 3);
+
+        // The literal engine is case-sensitive, unlike the regex default
+        let (output, _, _) = RosettaStone::convert_with_engine("x AND y", &LiteralEngine);
+        assert!(!output.contains('∧'));
+    }
+}
@@ -0,0 +1,211 @@
+//! Incremental conversion for live editing
+//!
+//! Keeps the converted output of each sentence so an edit only re-converts
+//! the sentences it touches.
+
+use crate::rosetta::RosettaStone;
+use lazy_static::lazy_static;
+use regex::Regex;
+use std::ops::Range;
+
+/// A converted sentence
+#[derive(Debug, Clone)]
+struct Segment {
+    /// Byte range in the source, including the trailing terminator and whitespace
+    source: Range<usize>,
+    /// Converted sentence followed by the original terminator and whitespace
+    output: String,
+}
+
+/// Result of applying an edit
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncrementalUpdate {
+    /// Full converted output after the edit
+    pub output: String,
+    /// Byte range of the edited source that was re-converted
+    pub source_span: Range<usize>,
+    /// Byte range of `output` that was re-converted
+    pub output_span: Range<usize>,
+}
+
+/// Stateful converter that re-converts only the sentences an edit touches
+///
+/// Output is the concatenation of per-sentence conversions, so applying an
+/// edit gives the same output as building a new converter from the edited text.
+///
+/// # Example
+/// ```
+/// use rosetta_aisp::IncrementalConverter;
+///
+/// let mut converter = IncrementalConverter::new("x and y. for all x in S.");
+/// let update = converter.apply_edit(2..5, "or");
+/// assert_eq!(update.output, "x∨y. ∀ x∈S.");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct IncrementalConverter {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+impl IncrementalConverter {
+    /// Convert a whole document
+    pub fn new(text: &str) -> Self {
+        IncrementalConverter {
+            source: text.to_string(),
+            segments: Self::convert_region(text, 0),
+        }
+    }
+
+    /// The current source text
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The current converted output
+    pub fn output(&self) -> String {
+        self.segments.iter().map(|s| s.output.as_str()).collect()
+    }
+
+    /// Source and output byte ranges of each converted sentence
+    pub fn spans(&self) -> Vec<(Range<usize>, Range<usize>)> {
+        let mut offset = 0;
+        self.segments
+            .iter()
+            .map(|segment| {
+                let output = offset..offset + segment.output.len();
+                offset = output.end;
+                (segment.source.clone(), output)
+            })
+            .collect()
+    }
+
+    /// Replace `range` of the source with `replacement` and re-convert the affected sentences
+    ///
+    /// # Panics
+    /// Panics if `range` is out of bounds or not on char boundaries, like `String::replace_range`.
+    pub fn apply_edit(&mut self, range: Range<usize>, replacement: &str) -> IncrementalUpdate {
+        self.source.replace_range(range.clone(), replacement);
+
+        // Re-convert from the sentence before the edit through the sentence after it,
+        // so merged or split sentences settle on unchanged boundaries
+        let last = self.segments.len().saturating_sub(1);
+        let first_touched = self
+            .segments
+            .iter()
+            .position(|s| s.source.end >= range.start)
+            .unwrap_or(last);
+        let last_touched = self
+            .segments
+            .iter()
+            .position(|s| s.source.end > range.end)
+            .unwrap_or(last);
+        let first = first_touched.saturating_sub(1);
+        let end = (last_touched + 2).min(self.segments.len());
+
+        let delta = replacement.len() as isize - (range.end - range.start) as isize;
+        let region_start = self.segments.get(first).map_or(0, |s| s.source.start);
+        let region_end = end
+            .checked_sub(1)
+            .and_then(|i| self.segments.get(i))
+            .map_or(self.source.len(), |s| {
+                (s.source.end as isize + delta) as usize
+            });
+
+        let replaced = Self::convert_region(&self.source[region_start..region_end], region_start);
+        let output_start: usize = self.segments[..first].iter().map(|s| s.output.len()).sum();
+        let output_len: usize = replaced.iter().map(|s| s.output.len()).sum();
+
+        for segment in &mut self.segments[end..] {
+            segment.source.start = (segment.source.start as isize + delta) as usize;
+            segment.source.end = (segment.source.end as isize + delta) as usize;
+        }
+        self.segments.splice(first..end, replaced);
+
+        IncrementalUpdate {
+            output: self.output(),
+            source_span: region_start..region_end,
+            output_span: output_start..output_start + output_len,
+        }
+    }
+
+    /// Split text into sentences and convert each, offsetting source ranges by `base`
+    fn convert_region(text: &str, base: usize) -> Vec<Segment> {
+        lazy_static! {
+            /// A sentence terminator or line break, with the whitespace after it
+            static ref TERMINATOR: Regex = Regex::new(r"[.!?](?:\s+|$)|\n\s*").unwrap();
+        }
+        let mut segments = Vec::new();
+        let mut start = 0;
+
+        let mut push = |body: Range<usize>, end: usize| {
+            let (converted, _, _) = RosettaStone::convert(&text[body.clone()]);
+            segments.push(Segment {
+                source: base + body.start..base + end,
+                output: converted + &text[body.end..end],
+            });
+        };
+
+        for m in TERMINATOR.find_iter(text) {
+            push(start..m.start(), m.end());
+            start = m.end();
+        }
+        if start < text.len() {
+            push(start..text.len(), text.len());
+        }
+
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_matches_full(converter: &IncrementalConverter) {
+        let full = IncrementalConverter::new(converter.source());
+        assert_eq!(converter.output(), full.output());
+        assert_eq!(converter.spans(), full.spans());
+    }
+
+    #[test]
+    fn test_edit_matches_full_reconvert() {
+        let mut converter =
+            IncrementalConverter::new("for all x in S. x and y!\nthere exists z. done");
+
+        // Within a sentence
+        let update = converter.apply_edit(18..21, "or");
+        assert_matches_full(&converter);
+        assert!(update.output.contains("x∨y!"));
+
+        // Merge two sentences by deleting a terminator
+        converter.apply_edit(14..15, " and");
+        assert_matches_full(&converter);
+
+        // Split a sentence in two
+        let at = converter.source().find("there").unwrap();
+        converter.apply_edit(at..at, "not x. ");
+        assert_matches_full(&converter);
+
+        // Append at the end, and replace everything
+        let len = converter.source().len();
+        converter.apply_edit(len..len, " for every ∀ item.");
+        assert_matches_full(&converter);
+        let len = converter.source().len();
+        converter.apply_edit(0..len, "x or y");
+        assert_matches_full(&converter);
+        assert_eq!(converter.output(), "x∨y");
+    }
+
+    #[test]
+    fn test_update_spans() {
+        let mut converter = IncrementalConverter::new("a. x and y. b. c.");
+        let update = converter.apply_edit(3..4, "z");
+        // Only the neighbouring sentences are re-converted
+        assert_eq!(update.source_span, 0..15);
+        assert_eq!(&update.output[update.output_span.clone()], "a. z∧y. b. ");
+
+        let mut empty = IncrementalConverter::new("");
+        let update = empty.apply_edit(0..0, "x and y");
+        assert_eq!(update.output, "x∧y");
+    }
+}
@@ -0,0 +1,198 @@
+//! Language packs - per-language prose patterns for the same AISP symbols
+//!
+//! The symbol targets are shared; only the prose patterns differ.
+
+use crate::rosetta::{RosettaEntry, ROSETTA};
+
+/// A set of prose patterns for one natural language
+pub trait LanguagePack {
+    /// Language code (e.g. "en", "es")
+    fn code(&self) -> &'static str;
+
+    /// Rosetta entries whose patterns are written in this language
+    fn entries(&self) -> &'static [RosettaEntry];
+}
+
+/// English patterns (the built-in Rosetta table)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct English;
+
+impl LanguagePack for English {
+    fn code(&self) -> &'static str {
+        "en"
+    }
+
+    fn entries(&self) -> &'static [RosettaEntry] {
+        ROSETTA
+    }
+}
+
+/// Spanish patterns (quantifiers, logic, sets, and comparisons)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Spanish;
+
+impl LanguagePack for Spanish {
+    fn code(&self) -> &'static str {
+        "es"
+    }
+
+    fn entries(&self) -> &'static [RosettaEntry] {
+        SPANISH
+    }
+}
+
+/// Spanish Rosetta mappings
+pub static SPANISH: &[RosettaEntry] = &[
+    // Quantifiers
+    RosettaEntry {
+        symbol: "∀",
+        patterns: &["para todo", "para todos", "para cada", "todo", "cada"],
+        category: "quantifier",
+    },
+    RosettaEntry {
+        symbol: "∃!",
+        patterns: &["existe exactamente un", "existe un único"],
+        category: "quantifier",
+    },
+    RosettaEntry {
+        symbol: "∃",
+        patterns: &["existe", "existen", "hay"],
+        category: "quantifier",
+    },
+    RosettaEntry {
+        symbol: "∄",
+        patterns: &["no existe", "no hay"],
+        category: "quantifier",
+    },
+    // Logic ("y" is left out: it is as often the variable y as "and")
+    RosettaEntry {
+        symbol: "∧",
+        patterns: &["además"],
+        category: "logic",
+    },
+    RosettaEntry {
+        symbol: "∨",
+        patterns: &["o", "o bien"],
+        category: "logic",
+    },
+    RosettaEntry {
+        symbol: "¬",
+        patterns: &["no"],
+        category: "logic",
+    },
+    RosettaEntry {
+        symbol: "⇒",
+        patterns: &["implica", "entonces", "por lo tanto"],
+        category: "logic",
+    },
+    RosettaEntry {
+        symbol: "⇔",
+        patterns: &["si y solo si", "equivale a"],
+        category: "logic",
+    },
+    // Definition
+    RosettaEntry {
+        symbol: "≜",
+        patterns: &["se define como", "definido como"],
+        category: "definition",
+    },
+    RosettaEntry {
+        symbol: "≡",
+        patterns: &["es idéntico a", "idéntico a"],
+        category: "definition",
+    },
+    // Sets
+    RosettaEntry {
+        symbol: "∉",
+        patterns: &["no pertenece a", "no está en"],
+        category: "set",
+    },
+    RosettaEntry {
+        symbol: "∈",
+        patterns: &["pertenece a", "en"],
+        category: "set",
+    },
+    RosettaEntry {
+        symbol: "∅",
+        patterns: &["conjunto vacío", "vacío"],
+        category: "set",
+    },
+    // Comparisons
+    RosettaEntry {
+        symbol: "≥",
+        patterns: &["mayor o igual que", "al menos"],
+        category: "comparison",
+    },
+    RosettaEntry {
+        symbol: "≤",
+        patterns: &["menor o igual que", "como máximo"],
+        category: "comparison",
+    },
+    RosettaEntry {
+        symbol: ">",
+        patterns: &["mayor que"],
+        category: "comparison",
+    },
+    RosettaEntry {
+        symbol: "<",
+        patterns: &["menor que"],
+        category: "comparison",
+    },
+    // Truth values
+    RosettaEntry {
+        symbol: "⊤",
+        patterns: &["verdadero"],
+        category: "truth",
+    },
+    RosettaEntry {
+        symbol: "⊥",
+        patterns: &["falso"],
+        category: "truth",
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rosetta::RosettaStone;
+
+    #[test]
+    fn test_spanish_quantifiers() {
+        let (result, mapped, _) = RosettaStone::convert_with_lang("para todo x en S", &Spanish);
+        assert_eq!(result, "∀ x∈S");
+        assert!(mapped > 0);
+
+        let (result, _, _) = RosettaStone::convert_with_lang("no existe x mayor que 5", &Spanish);
+        assert_eq!(result, "∄x > 5");
+    }
+
+    #[test]
+    fn test_spanish_y_stays_a_variable() {
+        let (result, _, _) = RosettaStone::convert_with_lang("x y y", &Spanish);
+        assert_eq!(result, "x y y");
+        let (result, _, _) = RosettaStone::convert_with_lang("p si y solo si q", &Spanish);
+        assert_eq!(result, "p⇔q");
+    }
+
+    #[test]
+    fn test_spanish_symbols_are_known() {
+        for entry in SPANISH {
+            assert!(
+                ROSETTA.iter().any(|e| e.symbol == entry.symbol),
+                "{} is not a Rosetta symbol",
+                entry.symbol
+            );
+        }
+    }
+
+    #[test]
+    fn test_english_pack_matches_convert() {
+        let input = "for all x in S, x is at least 5";
+        assert_eq!(
+            RosettaStone::convert_with_lang(input, &English),
+            RosettaStone::convert(input)
+        );
+        assert_eq!(English.code(), "en");
+        assert_eq!(Spanish.code(), "es");
+    }
+}
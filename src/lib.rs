@@ -11,11 +11,15 @@
 //! - **3-tier conversion**: Minimal, Standard, and Full conversion levels
 //! - **Round-trip support**: Convert prose → AISP → prose with semantic preservation
 //! - **Anti-drift guarantees**: Symbols maintain consistent meaning through conversions
+//! - **Pluggable matching**: swap the regex matcher for your own `MatchEngine`
+//! - **Syntax tree output**: `AispConverter::to_ast` parses a conversion into an `AispDocument`
+//! - **Markdown-aware conversion** (`markdown` feature): converts prose while keeping markup intact
+//! - **WASM bindings** (`wasm` feature): `convert` and `toProse` for browser use
 //!
 //! ## Quick Start
 //!
 //! ```rust
-//! use rosetta_aisp::{RosettaStone, AispConverter, ConversionTier};
+//! use rosetta_aisp::RosettaStone;
 //!
 //! // Simple prose to AISP conversion
 //! let (aisp, confidence, unmapped) = RosettaStone::convert("for all x in S");
@@ -26,10 +30,15 @@
 //! let prose = RosettaStone::to_prose(&aisp);
 //! assert!(prose.contains("for all"));
 //!
-//! // Full document conversion with auto tier detection
-//! let result = AispConverter::convert("Define a type User with id and name", None);
-//! println!("Tier: {}", result.tier);
-//! println!("Output: {}", result.output);
+//! // Full document conversion with auto tier detection (`documents` feature)
+//! #[cfg(feature = "documents")]
+//! {
+//!     use rosetta_aisp::AispConverter;
+//!
+//!     let result = AispConverter::convert("Define a type User with id and name", None);
+//!     println!("Tier: {}", result.tier);
+//!     println!("Output: {}", result.output);
+//! }
 //! ```
 //!
 //! ## Conversion Tiers
@@ -37,20 +46,71 @@
 //! - **Minimal**: Direct symbol substitution only (0.5-1x tokens)
 //! - **Standard**: Adds header, metadata, and evidence blocks (1.5-2x tokens)
 //! - **Full**: Complete AISP document with types, rules, and proofs (4-8x tokens)
+//!
+//! ## Cargo Features
+//!
+//! - `documents` (default): `AispConverter` with the Standard/Full tiers, validation,
+//!   and serde support. Disable it for a lean core with only `RosettaStone` and the
+//!   mappings, which depends on `regex` and `lazy_static` alone.
+//! - `serde` (enabled by `documents`): serialize `RosettaEntry` tables and load
+//!   custom ones with `RosettaStone::load_table`
+//! - `markdown`: `AispConverter::convert_markdown` via pulldown-cmark
+//! - `cache`: `AispConverter::with_cache` for an LRU-cached converter instance
+//! - `graphemes`: grapheme cluster counts in `TokenStats` sizes
+//! - `fuzzy`: report likely typos of known patterns in `ConversionResult::fuzzy_candidates`
+//! - `wasm`: wasm-bindgen bindings for browser use
 
+#[cfg(feature = "documents")]
+mod ast;
+#[cfg(feature = "cache")]
+mod cache;
+#[cfg(feature = "documents")]
 mod converter;
+#[cfg(feature = "documents")]
+mod diff;
+mod engine;
+mod incremental;
+mod lang;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "documents")]
+mod profile;
 mod rosetta;
+#[cfg(feature = "documents")]
+mod stats;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+#[cfg(feature = "documents")]
+pub use ast::{AispDocument, AispNode};
+#[cfg(feature = "cache")]
+pub use cache::CachedConverter;
+#[cfg(feature = "documents")]
 pub use converter::{
-    AispConverter, ConversionOptions, ConversionResult, ConversionTier, TokenStats,
+    AispConverter, BudgetedConversion, ConversionError, ConversionOptions, ConversionResult,
+    ConversionTier, FullBlock, FullTemplate, QualityScore, TextSize, TierPolicy, TokenStats,
+    ValidationError,
 };
+#[cfg(feature = "documents")]
+pub use diff::SymbolDiff;
+pub use engine::{CompiledTable, MatchEngine, RegexEngine, Substitution};
+pub use incremental::{IncrementalConverter, IncrementalUpdate};
+pub use lang::{English, LanguagePack, Spanish, SPANISH};
 pub use rosetta::{
-    get_all_categories, get_mapping_count, prose_to_symbol, symbol_to_prose, symbols_by_category,
-    CompiledRosettaEntry, RosettaEntry, RosettaStone, ROSETTA, ROSETTA_COMPILED, ROSETTA_SORTED,
+    band_of, band_of_category, category_of, entries_by_category, get_all_categories,
+    get_mapping_count, mapping_counts_by_category, prose_to_symbol, symbol_to_prose,
+    symbols_by_category, AispBand, CompiledRosettaEntry, ConversionMode, CorpusReport, EntryError,
+    GlossaryFormat, InvalidOverride, MappingCounts, MappingSuggestion, OwnedRosettaEntry,
+    PlannedSubstitution, ProseOptions, ProseOverrides, ProseStyle, ReverseIssue, RosettaEntry,
+    RosettaOptions, RosettaStone, StageSnapshot, AISP_BANDS, ROSETTA, ROSETTA_COMPILED,
+    ROSETTA_SORTED,
 };
+#[cfg(feature = "documents")]
+pub use stats::ConversionStats;
 
 /// Prelude for convenient imports
 pub mod prelude {
+    #[cfg(feature = "documents")]
     pub use crate::converter::{
         AispConverter, ConversionOptions, ConversionResult, ConversionTier, TokenStats,
     };
@@ -0,0 +1,137 @@
+//! Markdown-aware conversion
+//!
+//! Converts only the prose text nodes of a Markdown document.
+//! Headings, code blocks, code spans, links, and images are re-emitted unchanged.
+
+use crate::converter::{
+    AispConverter, ConversionOptions, ConversionResult, ConversionTier, TokenStats,
+};
+use crate::rosetta::{RosettaOptions, RosettaStone};
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use std::ops::Range;
+
+impl AispConverter {
+    /// Convert the prose body of a Markdown document to AISP symbols
+    ///
+    /// Only text nodes are converted, in place, so all surrounding markup
+    /// survives byte-for-byte.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::AispConverter;
+    ///
+    /// let result = AispConverter::convert_markdown("## Requirements\n\nfor all x in S, `x or y`\n");
+    /// assert!(result.output.starts_with("## Requirements\n"));
+    /// assert!(result.output.contains("∀"));
+    /// assert!(result.output.contains("`x or y`"));
+    /// ```
+    pub fn convert_markdown(md: &str) -> ConversionResult {
+        let mut skip_depth = 0usize;
+        let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+        let mut prose_chars = 0;
+        let mut applied = Vec::new();
+        let mut unmapped = Vec::new();
+        let mut warnings = Vec::new();
+
+        for (event, range) in Parser::new(md).into_offset_iter() {
+            match event {
+                Event::Start(
+                    Tag::Heading { .. } | Tag::CodeBlock(_) | Tag::Link { .. } | Tag::Image { .. },
+                ) => skip_depth += 1,
+                Event::End(
+                    TagEnd::Heading(_) | TagEnd::CodeBlock | TagEnd::Link | TagEnd::Image,
+                ) => skip_depth = skip_depth.saturating_sub(1),
+                Event::Text(_) if skip_depth == 0 => {
+                    let text = &md[range.clone()];
+                    let body = text.trim();
+                    if body.is_empty() {
+                        continue;
+                    }
+
+                    let (converted, subs, words) =
+                        RosettaStone::convert_detailed(body, &RosettaOptions::default());
+                    prose_chars += body.len();
+                    applied.extend(subs);
+                    unmapped.extend(words);
+
+                    // Keep the whitespace that separates this node from adjacent markup
+                    let leading = &text[..text.len() - text.trim_start().len()];
+                    warnings.extend(AispConverter::parenthesis_warnings(
+                        body,
+                        range.start + leading.len(),
+                    ));
+                    let trailing = &text[text.trim_end().len()..];
+                    replacements.push((range, format!("{}{}{}", leading, converted, trailing)));
+                }
+                _ => {}
+            }
+        }
+
+        let mut output = md.to_string();
+        for (range, converted) in replacements.into_iter().rev() {
+            output.replace_range(range, &converted);
+        }
+
+        unmapped.sort();
+        unmapped.dedup();
+        let category_chars = RosettaStone::tally_categories(&applied);
+        let mapped_chars = category_chars.values().sum();
+        #[cfg(feature = "fuzzy")]
+        let fuzzy_candidates = RosettaStone::fuzzy_candidates(&unmapped)
+            .into_iter()
+            .map(|(word, pattern)| (word, pattern.to_string()))
+            .collect();
+        #[cfg(not(feature = "fuzzy"))]
+        let fuzzy_candidates = Vec::new();
+
+        let confidence = RosettaStone::confidence(prose_chars, mapped_chars);
+        ConversionResult {
+            tokens: TokenStats::measure(md, &output),
+            output,
+            confidence,
+            category_confidence: RosettaStone::category_confidence(prose_chars, &category_chars),
+            confidence_weighted: RosettaStone::weighted_confidence(prose_chars, &applied),
+            unmapped,
+            fuzzy_candidates,
+            skipped_substitutions: Vec::new(),
+            pattern_counts: AispConverter::count_patterns(&applied),
+            warnings,
+            tier: ConversionTier::Minimal,
+            used_fallback: false,
+            below_threshold: confidence < ConversionOptions::DEFAULT_CONFIDENCE_THRESHOLD,
+            passthrough: false,
+            symbol_share: RosettaStone::symbol_share(md),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_markdown_preserves_markup() {
+        let md = "## Requirements or Goals\n\n- for all x in S\n- see [the docs](http://example.com/or)\n\n```\nx and y\n```\n";
+        let result = AispConverter::convert_markdown(md);
+
+        assert!(result.output.starts_with("## Requirements or Goals\n"));
+        assert!(result.output.contains("- ∀ x∈S\n"));
+        assert!(result.output.contains("[the docs](http://example.com/or)"));
+        assert!(result.output.contains("```\nx and y\n```"));
+    }
+
+    #[test]
+    fn test_convert_markdown_code_spans() {
+        let result = AispConverter::convert_markdown("x and `a or b` and y");
+        assert_eq!(result.output, "x∧ `a or b` ∧y");
+    }
+
+    #[test]
+    fn test_convert_markdown_parenthesis_warnings() {
+        let result = AispConverter::convert_markdown("# Title\n\nx and (y or z\n");
+        assert_eq!(
+            result.warnings,
+            vec!["unbalanced parentheses: unmatched '(' at byte 15"]
+        );
+    }
+}
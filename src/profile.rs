@@ -0,0 +1,155 @@
+//! Conversion profiles - named `ConversionOptions` presets
+//!
+//! Teams standardizing on a style can convert by name instead of repeating
+//! the same options at every call site.
+
+use crate::converter::{
+    AispConverter, ConversionError, ConversionOptions, ConversionResult, ConversionTier,
+};
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+lazy_static! {
+    /// Registered profiles, seeded with the built-in presets
+    static ref PROFILES: Mutex<HashMap<String, ConversionOptions>> = Mutex::new(
+        AispConverter::BUILTIN_PROFILES
+            .iter()
+            .map(|name| (name.to_string(), builtin(name)))
+            .collect()
+    );
+}
+
+/// Options for a built-in profile name
+fn builtin(name: &str) -> ConversionOptions {
+    match name {
+        "terse" => ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            ..Default::default()
+        },
+        "clauses" => ConversionOptions {
+            tier: Some(ConversionTier::Minimal),
+            clause_separator: Some("\n".to_string()),
+            ..Default::default()
+        },
+        "compact-spec" => ConversionOptions {
+            tier: Some(ConversionTier::Standard),
+            omit_empty_blocks: true,
+            ..Default::default()
+        },
+        "verbose-spec" => ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            annotate_source: true,
+            singularize: true,
+            ..Default::default()
+        },
+        _ => ConversionOptions::default(),
+    }
+}
+
+impl AispConverter {
+    /// Profiles available without registration
+    ///
+    /// - `terse`: Minimal tier
+    /// - `clauses`: Minimal tier, one clause per line
+    /// - `compact-spec`: Standard tier without empty blocks
+    /// - `verbose-spec`: Full tier with source annotations and singularized plurals
+    pub const BUILTIN_PROFILES: &'static [&'static str] =
+        &["terse", "clauses", "compact-spec", "verbose-spec"];
+
+    /// Register a named profile, replacing any profile (built-in or not) of that name
+    ///
+    /// Profiles are process-wide and visible from every thread.
+    pub fn register_profile(name: &str, options: ConversionOptions) {
+        PROFILES.lock().unwrap().insert(name.to_string(), options);
+    }
+
+    /// The options registered under a profile name
+    pub fn profile(name: &str) -> Option<ConversionOptions> {
+        PROFILES.lock().unwrap().get(name).cloned()
+    }
+
+    /// Names of all registered profiles, sorted
+    pub fn profile_names() -> Vec<String> {
+        let mut names: Vec<_> = PROFILES.lock().unwrap().keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// Convert prose with the options registered under a profile name
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{AispConverter, ConversionOptions, ConversionTier};
+    ///
+    /// let result = AispConverter::convert_with_profile("Define x as 5", "compact-spec").unwrap();
+    /// assert_eq!(result.tier, ConversionTier::Standard);
+    /// assert!(!result.output.contains("⟦Σ:Types⟧"));
+    ///
+    /// AispConverter::register_profile("team", ConversionOptions {
+    ///     tier: Some(ConversionTier::Minimal),
+    ///     ..Default::default()
+    /// });
+    /// assert!(AispConverter::convert_with_profile("Define x as 5", "team").is_ok());
+    /// assert!(AispConverter::convert_with_profile("Define x as 5", "missing").is_err());
+    /// ```
+    pub fn convert_with_profile(
+        prose: &str,
+        profile: &str,
+    ) -> Result<ConversionResult, ConversionError> {
+        let options = Self::profile(profile).ok_or_else(|| ConversionError::UnknownProfile {
+            name: profile.to_string(),
+        })?;
+        Ok(Self::convert(prose, Some(options)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_profiles() {
+        for name in AispConverter::BUILTIN_PROFILES {
+            assert!(
+                AispConverter::profile_names().contains(&name.to_string()),
+                "missing {}",
+                name
+            );
+        }
+
+        let prose = "Define x as 5. All users must have valid email.";
+        let clauses = AispConverter::convert_with_profile(prose, "clauses").unwrap();
+        assert_eq!(clauses.output.lines().count(), 2);
+
+        let verbose = AispConverter::convert_with_profile(prose, "verbose-spec").unwrap();
+        assert_eq!(verbose.tier, ConversionTier::Full);
+        assert!(verbose.output.contains("⟨prose:"));
+    }
+
+    #[test]
+    fn test_register_profile() {
+        AispConverter::register_profile(
+            "test-standard",
+            ConversionOptions {
+                tier: Some(ConversionTier::Standard),
+                ..Default::default()
+            },
+        );
+        let result = AispConverter::convert_with_profile("Define x as 5", "test-standard").unwrap();
+        assert_eq!(result.tier, ConversionTier::Standard);
+
+        let err =
+            AispConverter::convert_with_profile("Define x as 5", "no-such-profile").unwrap_err();
+        assert_eq!(
+            err,
+            ConversionError::UnknownProfile {
+                name: "no-such-profile".to_string()
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "unknown conversion profile \"no-such-profile\""
+        );
+    }
+}
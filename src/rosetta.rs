@@ -3,18 +3,58 @@
 //! Based on AISP 5.1 Σ_512 glossary specification.
 //! Ported from aisp-converter npm package.
 
+use crate::engine::{CompiledTable, MatchEngine, RegexEngine, Substitution};
+use crate::lang::LanguagePack;
+use aho_corasick::{AhoCorasick, MatchKind};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
+use std::ops::Range;
 
 /// Rosetta Stone mapping entry
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct RosettaEntry {
     pub symbol: &'static str,
     pub patterns: &'static [&'static str],
     pub category: &'static str,
 }
 
+/// A mapping entry that owns its strings, for tables built or loaded at runtime
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OwnedRosettaEntry {
+    pub symbol: String,
+    pub patterns: Vec<String>,
+    pub category: String,
+}
+
+impl OwnedRosettaEntry {
+    /// Convert to a `RosettaEntry` for `CompiledTable::new` and the other table APIs
+    ///
+    /// The strings are leaked to get their `'static` lifetime, so convert a
+    /// table once at startup rather than per conversion.
+    pub fn into_static(self) -> RosettaEntry {
+        let leak = |s: String| -> &'static str { Box::leak(s.into_boxed_str()) };
+        let patterns: Vec<&'static str> = self.patterns.into_iter().map(leak).collect();
+        RosettaEntry {
+            symbol: leak(self.symbol),
+            patterns: Box::leak(patterns.into_boxed_slice()),
+            category: leak(self.category),
+        }
+    }
+}
+
+impl From<&RosettaEntry> for OwnedRosettaEntry {
+    fn from(entry: &RosettaEntry) -> Self {
+        Self {
+            symbol: entry.symbol.to_string(),
+            patterns: entry.patterns.iter().map(|p| p.to_string()).collect(),
+            category: entry.category.to_string(),
+        }
+    }
+}
+
 /// Complete Rosetta Stone mappings (AISP 5.1 Σ_512)
 /// Ported from aisp-converter npm package
 pub static ROSETTA: &[RosettaEntry] = &[
@@ -28,7 +68,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "∃",
-        patterns: &["there exists", "exists", "some", "at least one", "there is"],
+        patterns: &["there exists", "exists", "some", "there is"],
         category: "quantifier",
     },
     RosettaEntry {
@@ -44,7 +84,20 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "∄",
-        patterns: &["does not exist", "no such", "none exists"],
+        patterns: &[
+            "does not exist",
+            "no such",
+            "none exists",
+            "there does not exist",
+            "there is no",
+            "there are no",
+        ],
+        category: "quantifier",
+    },
+    // Negated quantifiers outrank their "not" and "for all" components
+    RosettaEntry {
+        symbol: "¬∀",
+        patterns: &["not for all", "not every", "not all", "not each"],
         category: "quantifier",
     },
     // ═══════════════════════════════════════════════════════════════
@@ -62,7 +115,28 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "¬",
-        patterns: &["not", "negation", "isn't", "is not", "doesn't", "does not"],
+        patterns: &[
+            "not",
+            "negation",
+            "isn't",
+            "is not",
+            "doesn't",
+            "does not",
+            "cannot",
+            "can't",
+            "won't",
+            "don't",
+            "didn't",
+            "aren't",
+            "wasn't",
+            "weren't",
+            "hasn't",
+            "haven't",
+            "shouldn't",
+            "couldn't",
+            "wouldn't",
+            "mustn't",
+        ],
         category: "logic",
     },
     RosettaEntry {
@@ -78,25 +152,44 @@ pub static ROSETTA: &[RosettaEntry] = &[
         ],
         category: "logic",
     },
+    RosettaEntry {
+        symbol: "∣",
+        patterns: &["such that"],
+        category: "logic",
+    },
     RosettaEntry {
         symbol: "⇔",
         patterns: &[
             "if and only if",
             "iff",
+            "is equivalent to",
             "equivalent to",
             "is equivalent",
             "exactly when",
         ],
         category: "logic",
     },
+    // Arrows take one phrase each: ↦ sends a value to a value ("maps to"), → is
+    // what a function returns ("returns"), ⊢ is derivation ("yields", below).
+    // ↦ comes first so the "to" of → does not claim the end of "maps to".
+    RosettaEntry {
+        symbol: "↦",
+        patterns: &["maps to", "sends to", "mapsto"],
+        category: "definition",
+    },
     RosettaEntry {
         symbol: "→",
-        patterns: &["to", "returns", "maps to", "yields", "produces", "goes to"],
+        patterns: &["to", "returns", "produces", "goes to"],
         category: "logic",
     },
     RosettaEntry {
         symbol: "↔",
-        patterns: &["bidirectional", "two-way", "both ways"],
+        patterns: &[
+            "bidirectional",
+            "is bidirectionally related to",
+            "two-way",
+            "both ways",
+        ],
         category: "logic",
     },
     RosettaEntry {
@@ -127,6 +220,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
         symbol: "≥",
         patterns: &[
             "greater than or equal",
+            "greater than or equal to",
             "at least",
             "no less than",
             "minimum",
@@ -138,6 +232,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
         symbol: "≤",
         patterns: &[
             "less than or equal",
+            "less than or equal to",
             "at most",
             "no more than",
             "maximum",
@@ -149,6 +244,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
         symbol: "≡",
         patterns: &[
             "identical to",
+            "is identical to",
             "equals",
             "is equal to",
             "same as",
@@ -195,9 +291,18 @@ pub static ROSETTA: &[RosettaEntry] = &[
         patterns: &["assigned", "set to", "becomes", "gets", "is assigned", ":="],
         category: "definition",
     },
+    // Subtyping, not definition: matched before "is a" as the longer phrase
     RosettaEntry {
-        symbol: "↦",
-        patterns: &["mapsto", "maps to", "sends to"],
+        symbol: "⊑",
+        patterns: &[
+            "is a subtype of",
+            "is a specialization of",
+            "is a kind of",
+            "is a subclass of",
+            "subtype of",
+            "inherits from",
+            "extends",
+        ],
         category: "definition",
     },
     // ═══════════════════════════════════════════════════════════════
@@ -285,7 +390,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "∖",
-        patterns: &["set difference", "minus", "except", "without"],
+        patterns: &["set difference", "except", "without"],
         category: "set",
     },
     RosettaEntry {
@@ -379,7 +484,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "𝔹",
-        patterns: &["boolean", "bool", "true or false", "binary", "flag"],
+        patterns: &["boolean", "bool", "true or false", "boolean-valued", "binary", "flag"],
         category: "type",
     },
     RosettaEntry {
@@ -404,7 +509,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "Either",
-        patterns: &["either", "result", "union type"],
+        patterns: &["result"],
         category: "type",
     },
     // ═══════════════════════════════════════════════════════════════
@@ -434,7 +539,14 @@ pub static ROSETTA: &[RosettaEntry] = &[
     // ═══════════════════════════════════════════════════════════════
     RosettaEntry {
         symbol: "∎",
-        patterns: &["qed", "proven", "end of proof", "proved", "done"],
+        patterns: &[
+            "qed",
+            "proven",
+            "end of proof",
+            "proved",
+            "done",
+            "quod erat demonstrandum",
+        ],
         category: "special",
     },
     RosettaEntry {
@@ -449,7 +561,7 @@ pub static ROSETTA: &[RosettaEntry] = &[
     },
     RosettaEntry {
         symbol: "□",
-        patterns: &["necessarily", "always", "box"],
+        patterns: &["necessarily", "always", "henceforth", "globally", "box"],
         category: "special",
     },
     RosettaEntry {
@@ -458,11 +570,39 @@ pub static ROSETTA: &[RosettaEntry] = &[
         category: "special",
     },
     // ═══════════════════════════════════════════════════════════════
+    // TEMPORAL OPERATORS
+    // ═══════════════════════════════════════════════════════════════
+    RosettaEntry {
+        symbol: "○",
+        patterns: &["in the next state", "at the next step", "in the next step"],
+        category: "temporal",
+    },
+    RosettaEntry {
+        symbol: "𝒲",
+        patterns: &["weak until", "weakly until"],
+        category: "temporal",
+    },
+    RosettaEntry {
+        symbol: "𝒰",
+        patterns: &["until"],
+        category: "temporal",
+    },
+    RosettaEntry {
+        symbol: "𝒮",
+        patterns: &["ever since", "has held since"],
+        category: "temporal",
+    },
+    RosettaEntry {
+        symbol: "⊖",
+        patterns: &["previously", "in the previous state"],
+        category: "temporal",
+    },
+    // ═══════════════════════════════════════════════════════════════
     // MATH OPERATORS
     // ═══════════════════════════════════════════════════════════════
     RosettaEntry {
         symbol: "+",
-        patterns: &["plus", "added to", "sum of", "add"],
+        patterns: &["plus", "added to", "increased by", "add"],
         category: "math",
     },
     RosettaEntry {
@@ -480,6 +620,11 @@ pub static ROSETTA: &[RosettaEntry] = &[
         patterns: &["divided by", "over", "ratio of", "divide"],
         category: "math",
     },
+    RosettaEntry {
+        symbol: "mod",
+        patterns: &["modulo", "mod"],
+        category: "math",
+    },
     RosettaEntry {
         symbol: "²",
         patterns: &["squared", "square of", "to the power of 2"],
@@ -511,6 +656,19 @@ pub static ROSETTA: &[RosettaEntry] = &[
         category: "math",
     },
     // ═══════════════════════════════════════════════════════════════
+    // STATISTICS (probability and expectation are phrase passes: P(X∣Y), E[X])
+    // ═══════════════════════════════════════════════════════════════
+    RosettaEntry {
+        symbol: "Var",
+        patterns: &["variance"],
+        category: "statistics",
+    },
+    RosettaEntry {
+        symbol: "σ",
+        patterns: &["standard deviation", "std dev"],
+        category: "statistics",
+    },
+    // ═══════════════════════════════════════════════════════════════
     // BLOCK MARKERS (⟦⟧:Delimiters[384-447])
     // ═══════════════════════════════════════════════════════════════
     RosettaEntry {
@@ -587,17 +745,34 @@ pub static ROSETTA: &[RosettaEntry] = &[
 ];
 
 lazy_static! {
-    /// Rosetta entries sorted by longest pattern first (greedy matching)
-    pub static ref ROSETTA_SORTED: Vec<&'static RosettaEntry> = {
-        let mut entries: Vec<_> = ROSETTA.iter().collect();
-        entries.sort_by(|a, b| {
-            let max_a = a.patterns.iter().map(|p| p.len()).max().unwrap_or(0);
-            let max_b = b.patterns.iter().map(|p| p.len()).max().unwrap_or(0);
-            max_b.cmp(&max_a)
-        });
-        entries
+    /// Entries whose primary pattern `to_prose` reverses exactly
+    ///
+    /// Symbols shared by several entries (`μ`) and primary patterns shared by
+    /// several entries are left out. Other patterns of a kept entry stay in the
+    /// table, but `protect_lossy` holds their matches back as prose first.
+    pub(crate) static ref LOSSLESS_TABLE: Vec<RosettaEntry> = {
+        let unique = |entry: &RosettaEntry| {
+            ROSETTA
+                .iter()
+                .filter(|other| {
+                    other.symbol == entry.symbol || other.patterns.first() == entry.patterns.first()
+                })
+                .count()
+                == 1
+        };
+        ROSETTA
+            .iter()
+            .filter(|entry| !entry.patterns.is_empty() && unique(entry))
+            .cloned()
+            .collect()
     };
 
+    /// `LOSSLESS_TABLE` compiled once for the regex engine
+    pub(crate) static ref LOSSLESS_COMPILED: CompiledTable = CompiledTable::new(&LOSSLESS_TABLE);
+
+    /// Rosetta entries sorted by longest pattern first (greedy matching)
+    pub static ref ROSETTA_SORTED: Vec<&'static RosettaEntry> = sort_entries(ROSETTA);
+
     /// Pattern to symbol lookup
     pub static ref PATTERN_TO_SYMBOL: HashMap<String, &'static str> = {
         let mut m = HashMap::new();
@@ -609,6 +784,61 @@ lazy_static! {
         m
     };
 
+    /// Individual words appearing in any pattern (lowercased)
+    static ref PATTERN_WORDS: HashSet<String> = {
+        ROSETTA
+            .iter()
+            .flat_map(|entry| entry.patterns.iter())
+            .flat_map(|pattern| pattern.split_whitespace())
+            .map(|word| word.to_lowercase())
+            .collect()
+    };
+
+    /// Multi-word patterns written without spaces ("greaterthan"), longest first,
+    /// keyed by their lowercased concatenation
+    static ref CONCATENATED_PATTERNS: (Regex, HashMap<String, &'static str>) = {
+        const MIN_CONCATENATED_LEN: usize = 5;
+
+        let mut patterns: HashMap<String, &'static str> = HashMap::new();
+        for pattern in ROSETTA.iter().flat_map(|entry| entry.patterns.iter()) {
+            if !pattern.contains(' ')
+                || !pattern.chars().all(|c| c.is_ascii_alphabetic() || c == ' ')
+            {
+                continue;
+            }
+            let joined = pattern.replace(' ', "").to_lowercase();
+            if joined.len() >= MIN_CONCATENATED_LEN && !PATTERN_TO_SYMBOL.contains_key(&joined) {
+                patterns.entry(joined).or_insert(pattern);
+            }
+        }
+
+        let mut joined: Vec<_> = patterns.keys().cloned().collect();
+        joined.sort_by(|a, b| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+        let regex = Regex::new(&format!(r"(?i)\b(?:{})\b", joined.join("|"))).unwrap();
+        (regex, patterns)
+    };
+
+    /// Non-word symbols, longest first, for tokenizing AISP
+    static ref OPERATOR_SYMBOLS: Vec<&'static str> = {
+        let mut symbols: Vec<_> = ROSETTA
+            .iter()
+            .map(|e| e.symbol)
+            .filter(|s| !s.chars().all(char::is_alphanumeric))
+            .collect();
+        symbols.sort_by_key(|s| std::cmp::Reverse(s.len()));
+        symbols.dedup();
+        symbols
+    };
+
+    /// Symbol to category lookup (the first entry wins for shared symbols like `μ`)
+    static ref SYMBOL_TO_CATEGORY: HashMap<&'static str, &'static str> = {
+        let mut m = HashMap::new();
+        for entry in ROSETTA {
+            m.entry(entry.symbol).or_insert(entry.category);
+        }
+        m
+    };
+
     /// Symbol to primary pattern lookup
     pub static ref SYMBOL_TO_PATTERN: HashMap<&'static str, &'static str> = {
         let mut m = HashMap::new();
@@ -620,32 +850,484 @@ lazy_static! {
         m
     };
 
-    /// Compiled Rosetta entries for efficient matching
-    pub static ref ROSETTA_COMPILED: Vec<CompiledRosettaEntry> = {
-        ROSETTA_SORTED.iter().map(|entry| {
-            let compiled_patterns = entry.patterns.iter().filter_map(|pattern| {
-                let regex_str = format!(r"(?i)\b{}\b", escape_regex(pattern));
-                Regex::new(&regex_str).ok()
-            }).collect();
-            
+    /// Automaton over every symbol, with the entry each pattern index expands from
+    static ref SYMBOL_EXPANSIONS: (AhoCorasick, Vec<&'static RosettaEntry>) = {
+        let mut entries: Vec<&'static RosettaEntry> = Vec::new();
+        for entry in ROSETTA {
+            if !entries.iter().any(|e| e.symbol == entry.symbol) {
+                entries.push(entry);
+            }
+        }
+        let automaton = AhoCorasick::builder()
+            .match_kind(MatchKind::LeftmostLongest)
+            .build(entries.iter().map(|e| e.symbol))
+            .unwrap();
+        (automaton, entries)
+    };
+
+    /// Default phrase for each `SYMBOL_EXPANSIONS` pattern: its primary
+    /// pattern, or None to keep the symbol itself
+    static ref DEFAULT_EXPANSIONS: Vec<Option<&'static str>> = SYMBOL_EXPANSIONS
+        .1
+        .iter()
+        .map(|entry| entry.patterns.first().copied())
+        .collect();
+
+    /// The built-in table compiled for efficient matching (see `CompiledTable::builtin`)
+    pub static ref ROSETTA_COMPILED: CompiledTable = CompiledTable::new(ROSETTA);
+
+    /// Type entries with a symbol of their own ("ℤ", "𝕊"), in table order
+    static ref TYPE_ENTRIES: Vec<&'static RosettaEntry> = ROSETTA
+        .iter()
+        .filter(|e| e.category == "type" && !e.symbol.is_ascii())
+        .collect();
+
+    /// Type patterns with their symbols, longest pattern first
+    static ref TYPE_PATTERNS: Vec<(&'static str, &'static str)> = {
+        let mut types: Vec<_> = TYPE_ENTRIES
+            .iter()
+            .flat_map(|e| e.patterns.iter().map(move |p| (*p, e.symbol)))
+            .collect();
+        types.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.len()));
+        types
+    };
+
+    /// A typed quantifier over a short variable, with an optional collection and
+    /// constraint: "for every integer n in S greater than 0"
+    static ref TYPED_QUANTIFIER: Regex = {
+        let alternation: Vec<_> = TYPE_PATTERNS.iter().map(|(p, _)| escape_regex(p)).collect();
+        Regex::new(&format!(
+            r"(?i)\b(?:for\s+(?:all|every|each|any)|every|each)\s+(?P<type>{})s?\s+(?P<var>[a-z][0-9_]*)\b(?:\s+in\s+(?:the\s+)?(?P<set>[A-Za-z_]\w*)\b)?(?:\s+(?:is\s+)?(?P<comparator>(?:greater|less)\s+than(?:\s+or\s+equal\s+to)?|at\s+(?:least|most)|equal\s+to)\s+(?P<value>\w+))?",
+            alternation.join("|")
+        ))
+        .unwrap()
+    };
+}
+
+/// Sort entries by longest pattern (longest first)
+pub(crate) fn sort_entries(entries: &[RosettaEntry]) -> Vec<&RosettaEntry> {
+    let mut entries: Vec<_> = entries.iter().collect();
+    entries.sort_by(|a, b| {
+        let max_a = a.patterns.iter().map(|p| p.len()).max().unwrap_or(0);
+        let max_b = b.patterns.iter().map(|p| p.len()).max().unwrap_or(0);
+        max_b.cmp(&max_a)
+    });
+    entries
+}
+
+/// Compile word-bounded, case-insensitive regexes for each entry's patterns
+pub(crate) fn compile_entries(entries: &[&RosettaEntry]) -> Vec<CompiledRosettaEntry> {
+    entries
+        .iter()
+        .map(|entry| {
+            // Longest pattern first within an entry too ("is identical to" before "identical to")
+            let mut patterns: Vec<_> = entry.patterns.iter().collect();
+            patterns.sort_by_key(|p| std::cmp::Reverse(p.len()));
+
+            let (patterns, regexes) = patterns
+                .into_iter()
+                .filter_map(|pattern| {
+                    // Accept both straight and curly apostrophes in contractions
+                    let escaped = escape_regex(pattern).replace('\'', "['’]");
+                    // `\b` only holds next to a word char, so operators like ">=" go unanchored
+                    let is_word =
+                        |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+                    let start = if is_word(pattern.chars().next()) {
+                        r"\b"
+                    } else {
+                        ""
+                    };
+                    let end = if is_word(pattern.chars().last()) {
+                        r"\b"
+                    } else {
+                        ""
+                    };
+                    let regex_str = format!(r"(?i){}{}{}", start, escaped, end);
+                    Regex::new(&regex_str).ok().map(|regex| (*pattern, regex))
+                })
+                .unzip();
+
             CompiledRosettaEntry {
                 symbol: entry.symbol,
-                regexes: compiled_patterns,
+                category: entry.category,
+                patterns,
+                regexes,
             }
-        }).collect()
-    };
+        })
+        .collect()
+}
+
+/// (entry, pattern index) pairs in the order substitutions are applied
+///
+/// Operator patterns ("!==", ">=") go first, longest first across all entries,
+/// so "==" never claims the tail of "!=="; word patterns follow in entry order.
+pub(crate) fn match_order(entries: &[CompiledRosettaEntry]) -> Vec<(&CompiledRosettaEntry, usize)> {
+    let is_operator = |pattern: &str| !pattern.chars().any(char::is_alphanumeric);
+    let all = entries
+        .iter()
+        .flat_map(|entry| (0..entry.patterns.len()).map(move |i| (entry, i)));
+
+    let mut operators: Vec<_> = all
+        .clone()
+        .filter(|(entry, i)| is_operator(entry.patterns[*i]))
+        .collect();
+    operators.sort_by_key(|(entry, i)| std::cmp::Reverse(entry.patterns[*i].len()));
+
+    operators.extend(all.filter(|(entry, i)| !is_operator(entry.patterns[*i])));
+    operators
 }
 
 /// Pre-compiled Rosetta entry
+#[derive(Debug)]
 pub struct CompiledRosettaEntry {
     pub symbol: &'static str,
+    pub category: &'static str,
+    /// Source patterns, index-aligned with `regexes`
+    pub patterns: Vec<&'static str>,
     pub regexes: Vec<Regex>,
 }
 
+/// Which table substitutions `convert` may apply
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ConversionMode {
+    /// Apply every matching pattern (maximum compression)
+    #[default]
+    Compact,
+    /// Apply only substitutions `to_prose` reverses exactly
+    ///
+    /// A phrase is replaced only when it is its symbol's primary pattern,
+    /// written in lowercase, and no other entry shares that symbol, so
+    /// "returns" stays prose while "to" becomes `→`. The phrase passes
+    /// (bounds, ranges, typed quantifiers, ...) don't run. See
+    /// `RosettaStone::lossy_substitutions` for what is left out.
+    Lossless,
+}
+
+/// Output format of `RosettaStone::glossary`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GlossaryFormat {
+    /// A heading and table per category
+    #[default]
+    Markdown,
+    /// An array of categories, each with its band and entries
+    Json,
+}
+
+/// Options for the Rosetta conversion pass
+#[derive(Debug, Clone, Default)]
+pub struct RosettaOptions {
+    /// Join multi-word comparison subjects into snake_case identifiers
+    pub normalize_identifiers: bool,
+    /// Leave identifiers out of the unmapped words: words touching an operator
+    /// (`userId≔5`, `x∈items`) and camelCase names
+    pub exclude_identifiers: bool,
+    /// Which table substitutions to apply
+    pub mode: ConversionMode,
+    /// Skip folding smart quotes, dashes, and special spaces to ASCII (see `RosettaStone::sanitize`)
+    pub preserve_typography: bool,
+    /// Keep AISP symbols already in the input away from the phrase passes and
+    /// the table, so only the prose around them is converted
+    pub preserve_symbols: bool,
+    /// Compare adjectives as attributes of both sides: "A is more secure than B"
+    /// → "secure(A)>secure(B)". Off, adjectival comparisons stay prose.
+    pub symbolize_comparatives: bool,
+}
+
+/// How `to_prose` phrases quantified statements
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProseStyle {
+    /// Symbol-for-pattern expansion: "∀x∈S:P" → "for all x in S:P"
+    #[default]
+    Terse,
+    /// Grammatical phrasing: "∀x∈S:P" → "for all x in S, P holds"
+    Natural,
+}
+
+/// Preferred prose phrase per symbol, used by `to_prose_with` instead of the primary pattern
+///
+/// Each phrase must be one of the symbol's own patterns, so `convert` maps it back.
+///
+/// # Example
+/// ```
+/// use rosetta_aisp::{ProseOptions, ProseOverrides, RosettaStone};
+///
+/// let overrides = ProseOverrides::new().with("≜", "means").unwrap();
+/// let options = ProseOptions { overrides, ..Default::default() };
+/// assert_eq!(RosettaStone::to_prose_with("x≜5", &options), "x means 5");
+/// assert!(ProseOverrides::new().with("≜", "is").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProseOverrides {
+    phrases: HashMap<&'static str, &'static str>,
+}
+
+/// An override phrase that is not a pattern of its symbol
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidOverride {
+    pub symbol: String,
+    pub phrase: String,
+}
+
+impl std::fmt::Display for InvalidOverride {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\"{}\" is not a pattern for {}; convert would not map it back",
+            self.phrase, self.symbol
+        )
+    }
+}
+
+impl std::error::Error for InvalidOverride {}
+
+/// A table symbol that does not survive `to_prose` followed by `convert`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReverseIssue {
+    /// The symbol as it appears in the table
+    pub symbol: &'static str,
+    /// What `to_prose` made of the symbol
+    pub prose: String,
+    /// What `convert` made of that prose
+    pub converted: String,
+}
+
+impl std::fmt::Display for ReverseIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "\"{}\" reads as \"{}\", which converts to \"{}\"",
+            self.symbol, self.prose, self.converted
+        )
+    }
+}
+
+/// A Rosetta entry that would not convert reliably
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryError {
+    /// The symbol is empty
+    EmptySymbol,
+    /// The symbol contains whitespace, so tokenization would split it
+    WhitespaceInSymbol { symbol: String },
+    /// The symbol contains `$`, which regex replacement reads as a capture reference
+    DollarInSymbol { symbol: String },
+    /// A pattern is empty or only whitespace
+    EmptyPattern { symbol: String },
+    /// The same pattern appears twice (ignoring case)
+    DuplicatePattern { symbol: String, pattern: String },
+    /// A pattern matches inside a longer pattern of another entry, and is applied first
+    ShadowedPattern {
+        symbol: String,
+        pattern: String,
+        shadowed_symbol: String,
+        shadowed_pattern: String,
+    },
+}
+
+impl std::fmt::Display for EntryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntryError::EmptySymbol => write!(f, "entry has an empty symbol"),
+            EntryError::WhitespaceInSymbol { symbol } => {
+                write!(f, "symbol \"{}\" contains whitespace", symbol)
+            }
+            EntryError::DollarInSymbol { symbol } => {
+                write!(f, "symbol \"{}\" contains '$'", symbol)
+            }
+            EntryError::EmptyPattern { symbol } => {
+                write!(f, "entry for {} has an empty pattern", symbol)
+            }
+            EntryError::DuplicatePattern { symbol, pattern } => {
+                write!(f, "entry for {} repeats pattern \"{}\"", symbol, pattern)
+            }
+            EntryError::ShadowedPattern {
+                symbol,
+                pattern,
+                shadowed_symbol,
+                shadowed_pattern,
+            } => write!(
+                f,
+                "\"{}\" ({}) matches first inside \"{}\" ({})",
+                pattern, symbol, shadowed_pattern, shadowed_symbol
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EntryError {}
+
+impl ProseOverrides {
+    /// Create an empty override map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prefer `phrase` for `symbol`, if it is one of the symbol's patterns
+    pub fn with(mut self, symbol: &str, phrase: &str) -> Result<Self, InvalidOverride> {
+        let pattern = ROSETTA.iter().filter(|e| e.symbol == symbol).find_map(|e| {
+            e.patterns
+                .iter()
+                .find(|p| p.eq_ignore_ascii_case(phrase))
+                .map(|p| (e.symbol, *p))
+        });
+
+        match pattern {
+            Some((symbol, pattern)) => {
+                self.phrases.insert(symbol, pattern);
+                Ok(self)
+            }
+            None => Err(InvalidOverride {
+                symbol: symbol.to_string(),
+                phrase: phrase.to_string(),
+            }),
+        }
+    }
+
+    /// The preferred phrase for a symbol, if overridden
+    pub fn get(&self, symbol: &str) -> Option<&'static str> {
+        self.phrases.get(symbol).copied()
+    }
+}
+
+/// Options for converting AISP back to prose
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProseOptions {
+    pub style: ProseStyle,
+    pub overrides: ProseOverrides,
+    /// Capitalize the first word of each sentence ("for all x" → "For all x")
+    ///
+    /// Sentences open after `.`, `!`, or `?` and at line breaks. Only prose
+    /// vocabulary is capitalized; a sentence opening with an identifier
+    /// ("x defined as 5") keeps its case.
+    pub capitalize_sentences: bool,
+}
+
+/// The text after one stage of the conversion pipeline
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StageSnapshot {
+    /// Stage name, e.g. "substitution" or "assignments"
+    pub stage: &'static str,
+    /// Text after the stage ran, with escaped spans restored
+    pub output: String,
+}
+
+/// A substitution the converter would apply, located in the original text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlannedSubstitution {
+    /// Byte range of the matched phrase in the original input
+    pub span: Range<usize>,
+    /// Rosetta pattern that matched
+    pub pattern: &'static str,
+    /// Symbol that would replace the phrase
+    pub symbol: &'static str,
+}
+
+/// Number of entries (symbols) and prose patterns in a category
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MappingCounts {
+    pub entries: usize,
+    pub patterns: usize,
+}
+
+/// Round-trip verification report for a corpus of prose documents
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorpusReport {
+    /// Round-trip similarity for each document, in input order
+    pub scores: Vec<f64>,
+    /// Mean similarity across the corpus
+    pub mean_similarity: f64,
+    /// Indices of documents below the minimum similarity
+    pub failures: Vec<usize>,
+    /// Lowest-scoring documents as (index, similarity), worst first
+    pub worst: Vec<(usize, f64)>,
+    /// Whether every document met the minimum similarity
+    pub passed: bool,
+}
+
+/// A frequent unmapped word across a corpus, a candidate for a custom mapping
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MappingSuggestion {
+    /// Most frequent spelling of the word
+    pub word: String,
+    /// Other unmapped spellings sharing its stem ("validates", "validated"),
+    /// most frequent first
+    pub variants: Vec<String>,
+    /// Occurrences of the word and its variants across the corpus
+    pub occurrences: usize,
+    /// Number of documents that left the word or a variant unmapped
+    pub documents: usize,
+}
+
+/// A band of the AISP Σ_512 glossary, such as `∀:Quantifiers[128-191]`
+///
+/// `Display` renders the name and range: "Quantifiers[128-191]".
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AispBand {
+    /// Band glyph ("∀", "Ω")
+    pub glyph: &'static str,
+    /// Band name ("Quantifiers")
+    pub name: &'static str,
+    /// First glossary index in the band
+    pub start: u16,
+    /// Last glossary index in the band (inclusive)
+    pub end: u16,
+}
+
+impl std::fmt::Display for AispBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[{}-{}]", self.name, self.start, self.end)
+    }
+}
+
+/// Glossary bands, in index order
+pub static AISP_BANDS: &[AispBand] = &[
+    AispBand {
+        glyph: "Ω",
+        name: "Transmuters",
+        start: 0,
+        end: 63,
+    },
+    AispBand {
+        glyph: "Γ",
+        name: "Topologics",
+        start: 64,
+        end: 127,
+    },
+    AispBand {
+        glyph: "∀",
+        name: "Quantifiers",
+        start: 128,
+        end: 191,
+    },
+    AispBand {
+        glyph: "Δ",
+        name: "Contractors",
+        start: 192,
+        end: 255,
+    },
+    AispBand {
+        glyph: "𝔻",
+        name: "Domaines",
+        start: 256,
+        end: 319,
+    },
+    AispBand {
+        glyph: "Ψ",
+        name: "Intents",
+        start: 320,
+        end: 383,
+    },
+    AispBand {
+        glyph: "⟦⟧",
+        name: "Delimiters",
+        start: 384,
+        end: 447,
+    },
+];
+
 /// Find symbol for a prose pattern
 pub fn prose_to_symbol(pattern: &str) -> Option<&'static str> {
     PATTERN_TO_SYMBOL
-        .get(&pattern.to_lowercase().trim().to_string())
+        .get(&pattern.to_lowercase().trim().replace('’', "'"))
         .copied()
 }
 
@@ -654,11 +1336,51 @@ pub fn symbol_to_prose(symbol: &str) -> Option<&'static str> {
     SYMBOL_TO_PATTERN.get(symbol).copied()
 }
 
+/// Find the category of a symbol
+pub fn category_of(symbol: &str) -> Option<&'static str> {
+    SYMBOL_TO_CATEGORY.get(symbol).copied()
+}
+
+/// Find the glossary band a category belongs to
+///
+/// Categories outside the banded glossary (comparison, math, temporal, ...) have none.
+pub fn band_of_category(category: &str) -> Option<&'static AispBand> {
+    let name = match category {
+        "logic" | "definition" => "Transmuters",
+        "set" => "Topologics",
+        "quantifier" => "Quantifiers",
+        "contractor" => "Contractors",
+        "type" => "Domaines",
+        "intent" => "Intents",
+        "block" => "Delimiters",
+        _ => return None,
+    };
+    AISP_BANDS.iter().find(|band| band.name == name)
+}
+
+/// Find the glossary band of a symbol
+///
+/// # Example
+/// ```
+/// use rosetta_aisp::{band_of, category_of};
+///
+/// assert_eq!(category_of("∀"), Some("quantifier"));
+/// assert_eq!(band_of("∀").unwrap().to_string(), "Quantifiers[128-191]");
+/// assert_eq!(band_of(">"), None);
+/// ```
+pub fn band_of(symbol: &str) -> Option<&'static AispBand> {
+    category_of(symbol).and_then(band_of_category)
+}
+
+/// Get all entries in a category
+pub fn entries_by_category(category: &str) -> Vec<&'static RosettaEntry> {
+    ROSETTA.iter().filter(|e| e.category == category).collect()
+}
+
 /// Get all symbols in a category
 pub fn symbols_by_category(category: &str) -> Vec<&'static str> {
-    ROSETTA
-        .iter()
-        .filter(|e| e.category == category)
+    entries_by_category(category)
+        .into_iter()
         .map(|e| e.symbol)
         .collect()
 }
@@ -676,6 +1398,105 @@ pub fn get_mapping_count() -> usize {
     ROSETTA.iter().map(|e| e.patterns.len()).sum()
 }
 
+/// Count entries and patterns per category
+/// Pattern counts sum to `get_mapping_count()`
+pub fn mapping_counts_by_category() -> HashMap<&'static str, MappingCounts> {
+    let mut counts: HashMap<&'static str, MappingCounts> = HashMap::new();
+    for entry in ROSETTA {
+        let count = counts.entry(entry.category).or_default();
+        count.entries += 1;
+        count.patterns += entry.patterns.len();
+    }
+    counts
+}
+
+/// Check whether two words are within Levenshtein distance 1
+#[cfg(feature = "fuzzy")]
+fn within_one_edit(a: &str, b: &str) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (short, long) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+    if long.len() - short.len() > 1 {
+        return false;
+    }
+
+    // Skip the common prefix, then the remainders must match after one edit
+    let prefix = short
+        .iter()
+        .zip(long.iter())
+        .take_while(|(x, y)| x == y)
+        .count();
+    if prefix == short.len() {
+        return true;
+    }
+    if short.len() == long.len() {
+        short[prefix + 1..] == long[prefix + 1..]
+    } else {
+        short[prefix..] == long[prefix + 1..]
+    }
+}
+
+/// Superscript forms of exponent characters
+const SUPERSCRIPTS: [(char, char); 11] = [
+    ('0', '⁰'),
+    ('1', '¹'),
+    ('2', '²'),
+    ('3', '³'),
+    ('4', '⁴'),
+    ('5', '⁵'),
+    ('6', '⁶'),
+    ('7', '⁷'),
+    ('8', '⁸'),
+    ('9', '⁹'),
+    ('n', 'ⁿ'),
+];
+
+/// Map exponent digits (and `n`) to their superscript forms
+fn to_superscript(exponent: &str) -> String {
+    exponent
+        .chars()
+        .map(|c| {
+            SUPERSCRIPTS
+                .iter()
+                .find(|(plain, _)| *plain == c)
+                .map_or(c, |(_, sup)| *sup)
+        })
+        .collect()
+}
+
+/// Map superscript digits (and `ⁿ`) back to plain characters
+fn from_superscript(exponent: &str) -> String {
+    exponent
+        .chars()
+        .map(|c| {
+            SUPERSCRIPTS
+                .iter()
+                .find(|(_, sup)| *sup == c)
+                .map_or(c, |(plain, _)| *plain)
+        })
+        .collect()
+}
+
+/// Quote a string as a JSON string literal
+fn json_string(s: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 /// Escape regex special characters
 fn escape_regex(s: &str) -> String {
     let special = [
@@ -691,226 +1512,4518 @@ fn escape_regex(s: &str) -> String {
     result
 }
 
-/// Rosetta Stone converter
-pub struct RosettaStone;
+/// Cardinal and ordinal forms of the numbers below twenty, by value
+const SMALL_NUMBERS: [(&str, &str); 20] = [
+    ("zero", "zeroth"),
+    ("one", "first"),
+    ("two", "second"),
+    ("three", "third"),
+    ("four", "fourth"),
+    ("five", "fifth"),
+    ("six", "sixth"),
+    ("seven", "seventh"),
+    ("eight", "eighth"),
+    ("nine", "ninth"),
+    ("ten", "tenth"),
+    ("eleven", "eleventh"),
+    ("twelve", "twelfth"),
+    ("thirteen", "thirteenth"),
+    ("fourteen", "fourteenth"),
+    ("fifteen", "fifteenth"),
+    ("sixteen", "sixteenth"),
+    ("seventeen", "seventeenth"),
+    ("eighteen", "eighteenth"),
+    ("nineteen", "nineteenth"),
+];
 
-impl RosettaStone {
-    /// Convert prose to AISP symbols using deterministic mappings
-    /// Returns (converted_text, mapped_chars, unmapped_words)
-    pub fn convert(input: &str) -> (String, usize, Vec<String>) {
-        let mut result = input.to_string();
-        let mut mapped_chars = 0;
-        let _total_chars = input.len();
+/// Cardinal and ordinal forms of the tens, twenty upwards
+const TENS: [(&str, &str); 8] = [
+    ("twenty", "twentieth"),
+    ("thirty", "thirtieth"),
+    ("forty", "fortieth"),
+    ("fifty", "fiftieth"),
+    ("sixty", "sixtieth"),
+    ("seventy", "seventieth"),
+    ("eighty", "eightieth"),
+    ("ninety", "ninetieth"),
+];
 
-        // Apply Rosetta mappings (longest patterns first) using pre-compiled regexes
-        for entry in ROSETTA_COMPILED.iter() {
-            for regex in entry.regexes.iter() {
-                let matches: Vec<_> = regex.find_iter(&result).collect();
-                mapped_chars += matches.iter().map(|m| m.as_str().len()).sum::<usize>();
-                result = regex.replace_all(&result, entry.symbol).to_string();
-            }
+lazy_static! {
+    /// A spelled-out number below a thousand: "three", "twenty-first",
+    /// "a hundred", "one hundred and five"
+    static ref NUMERAL: Regex = {
+        let words = |forms: &[(&str, &str)]| {
+            forms
+                .iter()
+                .flat_map(|(cardinal, ordinal)| [*ordinal, *cardinal])
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+        let cardinals = |forms: &[(&str, &str)]| {
+            forms
+                .iter()
+                .map(|(cardinal, _)| *cardinal)
+                .collect::<Vec<_>>()
+                .join("|")
+        };
+        let units = words(&SMALL_NUMBERS[1..10]);
+        let below_hundred = format!(
+            r"(?:{tens_cardinal})[\s-]+(?:{units})|{tens}|{small}",
+            tens_cardinal = cardinals(&TENS),
+            units = units,
+            tens = words(&TENS),
+            small = words(&SMALL_NUMBERS),
+        );
+        let hundreds = format!(
+            r"(?:a|{units})\s+hundred(?:th\b|(?:\s+and)?\s+(?:{below_hundred})\b|\b)",
+            units = cardinals(&SMALL_NUMBERS[1..10]),
+            below_hundred = below_hundred,
+        );
+        Regex::new(&format!(r"(?i)\b(?:{}|(?:{})\b)", hundreds, below_hundred)).unwrap()
+    };
+}
+
+/// Value of a `NUMERAL` match, and whether it is an ordinal
+fn numeral_value(numeral: &str) -> (usize, bool) {
+    let mut value = 0;
+    let mut ordinal = false;
+    for word in numeral
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|word| !word.is_empty())
+    {
+        let word = word.to_lowercase();
+        let position = |forms: &[(&str, &str)]| {
+            forms
+                .iter()
+                .position(|(cardinal, ord)| *cardinal == word || *ord == word)
+                .map(|i| (i, forms[i].1 == word))
+        };
+        if let Some((n, is_ordinal)) = position(&SMALL_NUMBERS) {
+            value += n;
+            ordinal = is_ordinal;
+        } else if let Some((n, is_ordinal)) = position(&TENS) {
+            value += (n + 2) * 10;
+            ordinal = is_ordinal;
+        } else if word.starts_with("hundred") {
+            value = value.max(1) * 100;
+            ordinal = word == "hundredth";
         }
+    }
+    (value, ordinal)
+}
 
-        // Clean up operators (remove extra spaces)
-        result = Self::cleanup_operators(&result);
+/// "1st", "2nd", "3rd", "11th", "23rd"
+fn ordinal_suffix(n: usize) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
 
-        // Convert assignment patterns
-        result = Self::convert_assignments(&result);
+/// Digit for a small spelled-out number ("zero" → "0")
+fn number_word(word: &str) -> Option<&'static str> {
+    const WORDS: [&str; 11] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    const DIGITS: [&str; 11] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10"];
+    WORDS
+        .iter()
+        .position(|w| w.eq_ignore_ascii_case(word))
+        .map(|i| DIGITS[i])
+}
 
-        // Find unmapped words
-        let unmapped = Self::find_unmapped_words(&result);
+/// Placeholder delimiters for escaped spans (Unicode private use area)
+const ESCAPE_OPEN: char = '\u{E000}';
+const ESCAPE_CLOSE: char = '\u{E001}';
+const ESCAPE_DIGIT_BASE: u32 = 0xE010;
+/// Opens a placeholder for a preserved symbol run, restored apart from escapes
+const SYMBOL_OPEN: char = '\u{E002}';
 
-        (result.trim().to_string(), mapped_chars, unmapped)
-    }
+/// Swap `\aisp{...}` spans for placeholders no conversion pass touches
+fn protect_escapes(input: &str) -> (String, Vec<String>) {
+    let mut spans = Vec::new();
 
-    /// Calculate conversion confidence
-    pub fn confidence(input_len: usize, mapped_chars: usize) -> f64 {
-        if input_len == 0 {
-            return 1.0;
-        }
-        (mapped_chars as f64 / input_len as f64).min(1.0)
-    }
+    let protected = ESCAPED_SPAN.replace_all(input, |caps: &regex::Captures| {
+        let placeholder = escape_placeholder(spans.len());
+        spans.push(caps[0].to_string());
+        placeholder
+    });
 
-    /// Clean up operators by removing extra spaces
-    fn cleanup_operators(input: &str) -> String {
-        let operators = ["≜", "≔", "⇒", "∈", "→", "⇔", "∧", "∨"];
-        let mut result = input.to_string();
+    (protected.to_string(), spans)
+}
 
-        for op in operators {
-            let regex_str = format!(r"\s*{}\s*", escape_regex(op));
-            if let Ok(regex) = Regex::new(&regex_str) {
-                result = regex.replace_all(&result, op).to_string();
-            }
-        }
+/// Placeholder for the escaped span at `index`
+fn escape_placeholder(index: usize) -> String {
+    span_placeholder(ESCAPE_OPEN, index)
+}
 
-        result
-    }
+/// Placeholder opened by `open` for the span at `index`
+fn span_placeholder(open: char, index: usize) -> String {
+    let digits: String = index
+        .to_string()
+        .chars()
+        .filter_map(|d| char::from_u32(ESCAPE_DIGIT_BASE + d.to_digit(10).unwrap_or(0)))
+        .collect();
+    format!("{}{}{}", open, digits, ESCAPE_CLOSE)
+}
 
-    /// Convert common assignment patterns
+lazy_static! {
+    /// An `\aisp{...}` span, with backslash-escaped contents
+    static ref ESCAPED_SPAN: Regex = Regex::new(r"\\aisp\{((?:[^\\}]|\\.)*)\}").unwrap();
+
+    /// A span placeholder: its opener, then its index in private-use digits
+    static ref SPAN_PLACEHOLDER: Regex =
+        Regex::new("([\u{E000}\u{E002}\u{E003}])([\u{E010}-\u{E019}]+)\u{E001}").unwrap();
+
+    /// Inline conversion hint: `[[symbol:phrase]]`
+    static ref HINT: Regex = Regex::new(r"\[\[([^\s:\]]+):([^\]]+)\]\]").unwrap();
+}
+
+/// Swap `[[symbol:phrase]]` hints for placeholders that restore to the symbol
+/// Hints for table symbols are recorded as substitutions of their phrase
+fn protect_hints(input: &str, spans: &mut Vec<String>, applied: &mut Vec<Substitution>) -> String {
+    HINT.replace_all(input, |caps: &regex::Captures| {
+        if let Some(entry) = ROSETTA.iter().find(|e| e.symbol == &caps[1]) {
+            applied.push(Substitution {
+                matched: caps[2].to_string(),
+                pattern: entry.symbol,
+                symbol: entry.symbol,
+                category: entry.category,
+            });
+        }
+        let placeholder = escape_placeholder(spans.len());
+        spans.push(caps[1].to_string());
+        placeholder
+    })
+    .to_string()
+}
+
+/// Replace `[[symbol:phrase]]` hints with their phrase
+fn strip_hints(input: &str) -> String {
+    HINT.replace_all(input, "$2").to_string()
+}
+
+lazy_static! {
+    /// The set a quantifier binds its variable over: the `S` of `∀x∈S:`
+    static ref BOUND_SET: Regex =
+        Regex::new(r"(?P<binder>[∀∃∄]!?[A-Za-z_]\w*∈)(?P<set>[A-Za-z_]\w*):").unwrap();
+}
+
+/// Swap the set name of a written `∀x∈S:` binding for an escape placeholder,
+/// so re-converting "∀item∈list:" keeps its set instead of reading the List type
+fn protect_bound_sets(input: &str, spans: &mut Vec<String>) -> String {
+    BOUND_SET
+        .replace_all(input, |caps: &regex::Captures| {
+            let placeholder = escape_placeholder(spans.len());
+            spans.push(caps["set"].to_string());
+            format!("{}{}:", &caps["binder"], placeholder)
+        })
+        .to_string()
+}
+
+lazy_static! {
+    /// A word of three or more letters, as counted by the unmapped-word reports
+    static ref WORD: Regex = Regex::new(r"\b[a-zA-Z]{3,}\b").unwrap();
+
+    /// Separators in an enumeration: "a, b, and c", "x and y"
+    static ref LIST_SEPARATOR: Regex = Regex::new(r"(?i)\s*,\s*(?:and\s+)?|\s+and\s+").unwrap();
+
+    /// Runs of non-ASCII table symbols ("∀", "∈", "≜", "ℤ")
+    ///
+    /// ASCII symbols are left out: they are also operator patterns (">", "->").
+    static ref EXISTING_SYMBOLS: Regex = {
+        let mut symbols: Vec<_> = ROSETTA
+            .iter()
+            .map(|e| e.symbol)
+            .filter(|s| !s.is_ascii())
+            .collect();
+        symbols.sort_by_key(|s| std::cmp::Reverse(s.len()));
+        symbols.dedup();
+        let alternation: Vec<_> = symbols.iter().map(|s| regex::escape(s)).collect();
+        Regex::new(&format!("(?:{})+", alternation.join("|"))).unwrap()
+    };
+}
+
+/// Swap symbol runs already in the input for placeholders, restored by `restore_symbols`
+fn protect_symbols(input: &str, symbols: &mut Vec<String>) -> String {
+    EXISTING_SYMBOLS
+        .replace_all(input, |caps: &regex::Captures| {
+            let placeholder = span_placeholder(SYMBOL_OPEN, symbols.len());
+            symbols.push(caps[0].to_string());
+            placeholder
+        })
+        .to_string()
+}
+
+/// Swap table matches `to_prose` can't give back exactly for escape
+/// placeholders, recording each as skipped
+///
+/// The whole table is matched in `convert`'s order, so a longer phrase
+/// ("greater than or equal to") claims its words before a primary pattern
+/// inside it ("greater than or equal"). Only a kept entry's primary pattern,
+/// written in its own case, is left for the table.
+fn protect_lossy(input: &str, spans: &mut Vec<String>, skipped: &mut Vec<Substitution>) -> String {
+    /// Opens a placeholder for a primary pattern held while the scan runs
+    const KEPT_OPEN: char = '\u{E003}';
+
+    let mut kept = Vec::new();
+    let mut result = input.to_string();
+    for (entry, i) in match_order(ROSETTA_COMPILED.entries()) {
+        let pattern = entry.patterns[i];
+        let primary = LOSSLESS_TABLE
+            .iter()
+            .any(|e| e.symbol == entry.symbol && e.patterns[0] == pattern);
+        result = entry.regexes[i]
+            .replace_all(&result, |caps: &regex::Captures| {
+                let matched = caps[0].to_string();
+                if primary && matched == pattern {
+                    kept.push(matched);
+                    return span_placeholder(KEPT_OPEN, kept.len() - 1);
+                }
+                skipped.push(Substitution {
+                    matched: matched.clone(),
+                    pattern,
+                    symbol: entry.symbol,
+                    category: entry.category,
+                });
+                spans.push(matched);
+                escape_placeholder(spans.len() - 1)
+            })
+            .to_string();
+    }
+    restore_spans(&result, KEPT_OPEN, &kept)
+}
+
+/// Byte ranges of `original` tokens that `phrased` no longer has
+///
+/// Tokens are whitespace-separated and compared line by line, after
+/// sanitizing, by their longest common subsequence. When the line counts
+/// differ, lines are compared as one.
+fn rewritten_spans(original: &str, phrased: &str) -> Vec<Range<usize>> {
+    lazy_static! {
+        static ref TOKEN: Regex = Regex::new(r"\S+").unwrap();
+    }
+    let lines = |text: &str| -> Vec<Range<usize>> {
+        let mut start = 0;
+        let mut ranges = Vec::new();
+        for line in text.split('\n') {
+            ranges.push(start..start + line.len());
+            start += line.len() + 1;
+        }
+        ranges
+    };
+    let (mut original_lines, mut phrased_lines) = (lines(original), lines(phrased));
+    if original_lines.len() != phrased_lines.len() {
+        original_lines = std::iter::once(0..original.len()).collect();
+        phrased_lines = std::iter::once(0..phrased.len()).collect();
+    }
+
+    let mut rewritten = Vec::new();
+    for (a, b) in original_lines.into_iter().zip(phrased_lines) {
+        let ours: Vec<_> = TOKEN
+            .find_iter(&original[a.clone()])
+            .map(|m| (m.start() + a.start)..(m.end() + a.start))
+            .collect();
+        let theirs: Vec<_> = TOKEN.find_iter(&phrased[b]).map(|m| m.as_str()).collect();
+        let same = |i: usize, j: usize| RosettaStone::sanitize(&original[ours[i].clone()]) == theirs[j];
+
+        // Common prefix and suffix first, so repeated words align where they stand
+        let mut prefix = 0;
+        while prefix < ours.len() && prefix < theirs.len() && same(prefix, prefix) {
+            prefix += 1;
+        }
+        let mut suffix = 0;
+        while suffix < ours.len() - prefix
+            && suffix < theirs.len() - prefix
+            && same(ours.len() - 1 - suffix, theirs.len() - 1 - suffix)
+        {
+            suffix += 1;
+        }
+        let (n, m) = (ours.len() - prefix - suffix, theirs.len() - prefix - suffix);
+
+        // Longest common subsequence of the middle
+        let mut lengths = vec![vec![0u32; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lengths[i][j] = if same(prefix + i, prefix + j) {
+                    lengths[i + 1][j + 1] + 1
+                } else {
+                    lengths[i + 1][j].max(lengths[i][j + 1])
+                };
+            }
+        }
+        let (mut i, mut j) = (0, 0);
+        while i < n {
+            if j < m && same(prefix + i, prefix + j) {
+                i += 1;
+                j += 1;
+            } else if j < m && lengths[i][j + 1] >= lengths[i + 1][j] {
+                j += 1;
+            } else {
+                rewritten.push(ours[prefix + i].clone());
+                i += 1;
+            }
+        }
+    }
+    rewritten
+}
+
+/// Put escaped spans back in place of their placeholders
+fn restore_escapes(input: &str, spans: &[String]) -> String {
+    restore_spans(input, ESCAPE_OPEN, spans)
+}
+
+/// Put preserved symbol runs back in place of their placeholders
+fn restore_symbols(input: &str, symbols: &[String]) -> String {
+    restore_spans(input, SYMBOL_OPEN, symbols)
+}
+
+/// Put spans back in place of the placeholders opened by `open`
+fn restore_spans(input: &str, open: char, spans: &[String]) -> String {
+    if spans.is_empty() {
+        return input.to_string();
+    }
+
+    SPAN_PLACEHOLDER
+        .replace_all(input, |caps: &regex::Captures| {
+            if !caps[1].starts_with(open) {
+                return caps[0].to_string();
+            }
+            let index: usize = caps[2]
+                .chars()
+                .map(|d| char::from_digit(d as u32 - ESCAPE_DIGIT_BASE, 10).unwrap_or('0'))
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0);
+            spans.get(index).cloned().unwrap_or_default()
+        })
+        .to_string()
+}
+
+/// Rosetta Stone converter
+pub struct RosettaStone;
+
+impl RosettaStone {
+    /// Convert prose to AISP symbols using deterministic mappings
+    /// Returns (converted_text, mapped_chars, unmapped_words)
+    pub fn convert(input: &str) -> (String, usize, Vec<String>) {
+        let (result, category_chars, unmapped) = Self::convert_by_category(input);
+        (result, category_chars.values().sum(), unmapped)
+    }
+
+    /// Convert prose to AISP symbols, attributing mapped chars to symbol categories
+    /// Returns (converted_text, mapped_chars_per_category, unmapped_words)
+    pub fn convert_by_category(input: &str) -> (String, HashMap<&'static str, usize>, Vec<String>) {
+        Self::convert_with(input, &RosettaOptions::default())
+    }
+
+    /// Convert prose to AISP symbols with explicit pass options
+    /// Returns (converted_text, mapped_chars_per_category, unmapped_words)
+    pub fn convert_with(
+        input: &str,
+        options: &RosettaOptions,
+    ) -> (String, HashMap<&'static str, usize>, Vec<String>) {
+        Self::convert_with_options_and_engine(input, options, &RegexEngine)
+    }
+
+    /// Convert prose to AISP symbols, substituting patterns with a custom match engine
+    /// Returns (converted_text, mapped_chars, unmapped_words)
+    ///
+    /// The engine only replaces the table step; the phrase passes still run around it.
+    pub fn convert_with_engine(
+        input: &str,
+        engine: &dyn MatchEngine,
+    ) -> (String, usize, Vec<String>) {
+        let (result, category_chars, unmapped) =
+            Self::convert_with_options_and_engine(input, &RosettaOptions::default(), engine);
+        (result, category_chars.values().sum(), unmapped)
+    }
+
+    /// Convert prose to AISP symbols with a pre-compiled table (the built-in table if None)
+    /// Returns (converted_text, mapped_chars, unmapped_words)
+    ///
+    /// Compile a custom table once with `CompiledTable::try_new` and pass it to every
+    /// call; the phrase passes still run around it.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{CompiledTable, RosettaEntry, RosettaStone};
+    ///
+    /// static TABLE: &[RosettaEntry] = &[RosettaEntry {
+    ///     symbol: "⊕",
+    ///     patterns: &["xor"],
+    ///     category: "logic",
+    /// }];
+    /// let table = CompiledTable::try_new(TABLE).unwrap();
+    /// let (output, _, _) = RosettaStone::convert_with_table("a xor b and c", Some(&table));
+    /// assert_eq!(output, "a ⊕ b and c");
+    ///
+    /// let (output, _, _) = RosettaStone::convert_with_table("a and b", None);
+    /// assert_eq!(output, RosettaStone::convert("a and b").0);
+    /// ```
+    pub fn convert_with_table(
+        input: &str,
+        table: Option<&CompiledTable>,
+    ) -> (String, usize, Vec<String>) {
+        let table = table.unwrap_or_else(|| CompiledTable::builtin());
+        let (result, applied, unmapped) = Self::run_pipeline(
+            input,
+            &RosettaOptions::default(),
+            table.table(),
+            table,
+            None,
+        );
+        (
+            result,
+            Self::tally_categories(&applied).values().sum(),
+            unmapped,
+        )
+    }
+
+    /// Convert prose to AISP symbols, keeping every substitution applied
+    /// Returns (converted_text, substitutions in application order, unmapped_words)
+    pub fn convert_detailed(
+        input: &str,
+        options: &RosettaOptions,
+    ) -> (String, Vec<Substitution>, Vec<String>) {
+        Self::run_pipeline(
+            input,
+            options,
+            Self::mode_table(options.mode),
+            &RegexEngine,
+            None,
+        )
+    }
+
+    fn convert_with_options_and_engine(
+        input: &str,
+        options: &RosettaOptions,
+        engine: &dyn MatchEngine,
+    ) -> (String, HashMap<&'static str, usize>, Vec<String>) {
+        let table = Self::mode_table(options.mode);
+        let (result, applied, unmapped) = Self::run_pipeline(input, options, table, engine, None);
+        (result, Self::tally_categories(&applied), unmapped)
+    }
+
+    /// Convert prose to AISP symbols, recording the text after each pipeline stage
+    /// Returns (converted_text, snapshots in stage order)
+    ///
+    /// Use it to find which stage mangled an output; `convert` records nothing.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// let (output, stages) = RosettaStone::convert_traced("x is at least 5");
+    /// let substitution = stages.iter().find(|s| s.stage == "substitution").unwrap();
+    /// assert_eq!(substitution.output, "x is ≥ 5");
+    /// assert_eq!(output, "x≥5");
+    /// ```
+    pub fn convert_traced(input: &str) -> (String, Vec<StageSnapshot>) {
+        let mut stages = Vec::new();
+        let (result, _, _) = Self::run_pipeline(
+            input,
+            &RosettaOptions::default(),
+            ROSETTA,
+            &RegexEngine,
+            Some(&mut stages),
+        );
+        (result, stages)
+    }
+
+    /// The table the substitution step matches in each mode
+    fn mode_table(mode: ConversionMode) -> &'static [RosettaEntry] {
+        match mode {
+            ConversionMode::Compact => ROSETTA,
+            ConversionMode::Lossless => &LOSSLESS_TABLE,
+        }
+    }
+
+    /// The full English pipeline, returning every substitution applied along the way
+    fn run_pipeline(
+        input: &str,
+        options: &RosettaOptions,
+        table: &[RosettaEntry],
+        engine: &dyn MatchEngine,
+        mut trace: Option<&mut Vec<StageSnapshot>>,
+    ) -> (String, Vec<Substitution>, Vec<String>) {
+        // Escaped spans are kept verbatim
+        let (protected, mut spans) = protect_escapes(input);
+        let mut applied = Vec::new();
+
+        // Hinted phrases become their symbol, whatever the matcher would say
+        let protected = protect_hints(&protected, &mut spans, &mut applied);
+
+        // Set names bound by a written quantifier are names, not vocabulary
+        let protected = protect_bound_sets(&protected, &mut spans);
+
+        // Symbols already in the input sit out the phrase passes and the table
+        let mut symbols = Vec::new();
+        let protected = if options.preserve_symbols {
+            protect_symbols(&protected, &mut symbols)
+        } else {
+            protected
+        };
+
+        // Word-processor quotes, dashes, and spaces become ASCII
+        let sanitized = if options.preserve_typography {
+            protected.clone()
+        } else {
+            Self::sanitize(&protected)
+        };
+
+        // Phrases `to_prose` can't give back exactly sit out the table like escapes
+        let sanitized = match options.mode {
+            ConversionMode::Compact => sanitized,
+            ConversionMode::Lossless => protect_lossy(&sanitized, &mut spans, &mut Vec::new()),
+        };
+
+        let mut record = |stage: &'static str, text: &str, spans: &[String]| {
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(StageSnapshot {
+                    stage,
+                    output: restore_escapes(&restore_symbols(text, &symbols), spans),
+                });
+            }
+        };
+        record("hints", &protected, &spans);
+        record("sanitize", &sanitized, &spans);
+
+        // Phrase passes rewrite whole phrases `to_prose` can't rebuild, so
+        // lossless mode goes straight to the table
+        let phrase_passes = options.mode == ConversionMode::Compact;
+        let mut result = sanitized;
+        if phrase_passes {
+            // Caret and "to the power of" exponents become superscripts
+            result = Self::convert_exponents(&result);
+            record("exponents", &result, &spans);
+
+            // Absolute values and factorials take their postfix and bar notation
+            result = Self::convert_arithmetic(&result);
+            record("arithmetic", &result, &spans);
+
+            // Claim "at least two of" before "at least" becomes ≥
+            result = Self::convert_counting_quantifiers(&result);
+            record("counting", &result, &spans);
+
+            // Claim "set of all" before "all" becomes a quantifier
+            result = Self::convert_set_builders(&result);
+            record("set_builders", &result, &spans);
+
+            // Claim "the number of elements in S" before "number" becomes ℝ
+            result = Self::convert_cardinality(&result);
+            record("cardinality", &result, &spans);
+
+            // Claim "ratio of" before it becomes ÷
+            result = Self::convert_quantities(&result);
+            record("quantities", &result, &spans);
+
+            // Phrase "probability of X given Y" as P(X∣Y)
+            result = Self::convert_statistics(&result);
+            record("statistics", &result, &spans);
+
+            // Compare "A is more secure than B" by attribute, when asked
+            if options.symbolize_comparatives {
+                result = Self::convert_comparatives(&result);
+            }
+            record("comparatives", &result, &spans);
+
+            // Bind "between 1 and 100" before its "and" becomes ∧
+            result = Self::convert_ranges(&result);
+            record("ranges", &result, &spans);
+
+            // Enumerations become literals before "list" becomes the List type
+            result = Self::convert_list_literals(&result);
+            record("lists", &result, &spans);
+
+            // Bind "for all x and y in S" before its "and" becomes ∧
+            result = Self::convert_bound_variables(&result);
+            record("bound_variables", &result, &spans);
+
+            // Bind "for every integer n ..." into a typed quantifier
+            result = Self::convert_typed_quantifiers(&result);
+            record("typed_quantifiers", &result, &spans);
+
+            // Bind "for each x in S, do something to it" before "each" becomes ∀
+            result = Self::convert_imperative_quantifiers(&result, &mut spans);
+            record("imperative_quantifiers", &result, &spans);
+
+            // Claim "a function from A to B" before "function" becomes λ
+            result = Self::convert_signatures(&result);
+            record("signatures", &result, &spans);
+        }
+
+        // Apply Rosetta mappings through the match engine (regex, longest patterns first, by default)
+        result = Self::apply_entries(&result, table, engine, &mut applied);
+        result = restore_symbols(&result, &symbols);
+        record("substitution", &result, &spans);
+
+        // Clean up operators (remove extra spaces)
+        result = Self::cleanup_operators(&result);
+        record("cleanup_operators", &result, &spans);
+
+        if phrase_passes {
+            // A quantifier's "such that" and a probability's "given" become ∣
+            result = Self::convert_conditions(&result);
+            record("conditions", &result, &spans);
+
+            // Give conjoined comparisons their shared subject
+            result = Self::convert_comparison_chains(&result);
+            record("comparison_chains", &result, &spans);
+
+            // Bind "<subject> must be <comparator> <value><unit>" into one comparison
+            result = Self::convert_bounds(&result, options.normalize_identifiers);
+            record("bounds", &result, &spans);
+
+            // Convert assignment patterns
+            result = Self::convert_assignments(&result);
+            record("assignments", &result, &spans);
+
+            // Spell out both branches of if/then/else
+            result = Self::convert_conditionals(&result);
+            record("conditionals", &result, &spans);
+        }
+
+        // Drop spaces around operators inside set-builder braces
+        result = Self::tighten_set_builders(&result);
+        record("tighten_set_builders", &result, &spans);
+
+        // Drop spaces just inside matched parentheses, leaving unmatched ones as written
+        result = Self::tighten_brackets(&result, &['(']);
+        record("groups", &result, &spans);
+
+        // Find unmapped words
+        let unmapped = Self::find_unmapped_words(&result, options.exclude_identifiers);
+
+        let result = restore_escapes(result.trim(), &spans);
+        (result, applied, unmapped)
+    }
+
+    /// Convert prose in another language to AISP symbols
+    /// Returns (converted_text, mapped_chars, unmapped_words)
+    ///
+    /// Only symbol substitution and operator cleanup run for non-English packs;
+    /// the English phrase passes (bounds, assignments, conditionals) are skipped.
+    /// Each pack's patterns are compiled once, on first use.
+    pub fn convert_with_lang(input: &str, lang: &dyn LanguagePack) -> (String, usize, Vec<String>) {
+        if std::ptr::eq(lang.entries(), ROSETTA) {
+            return Self::convert(input);
+        }
+
+        let mut applied = Vec::new();
+
+        let (protected, mut spans) = protect_escapes(input);
+        let protected = protect_hints(&protected, &mut spans, &mut applied);
+        let mut result = Self::convert_exponents(&protected);
+        let engine = CompiledTable::cached(lang.entries());
+        result = Self::apply_entries(&result, lang.entries(), engine, &mut applied);
+        result = Self::cleanup_operators(&result);
+        result = Self::convert_comparison_chains(&result);
+
+        let unmapped = Self::find_unmapped_words(&result, false);
+        (
+            restore_escapes(result.trim(), &spans),
+            applied.iter().map(|sub| sub.matched.len()).sum(),
+            unmapped,
+        )
+    }
+
+    /// Replace every pattern match with its entry's symbol, recording the substitutions
+    fn apply_entries(
+        input: &str,
+        table: &[RosettaEntry],
+        engine: &dyn MatchEngine,
+        applied: &mut Vec<Substitution>,
+    ) -> String {
+        let (result, substitutions) = engine.convert(input, table);
+        applied.extend(substitutions);
+        result
+    }
+
+    /// Mapped chars per category across a set of substitutions
+    pub(crate) fn tally_categories(applied: &[Substitution]) -> HashMap<&'static str, usize> {
+        let mut category_chars: HashMap<&'static str, usize> = HashMap::new();
+        for sub in applied {
+            *category_chars.entry(sub.category).or_insert(0) += sub.matched.len();
+        }
+        category_chars
+    }
+
+    /// Substitutions `convert` would apply that `to_prose` can't reverse exactly
+    ///
+    /// These are the table matches `ConversionMode::Lossless` skips: synonyms
+    /// that expand back to a different phrase, phrases not written in their
+    /// pattern's case, and symbols shared by several entries.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{ConversionMode, RosettaOptions, RosettaStone};
+    ///
+    /// let lossy = RosettaStone::lossy_substitutions("f returns y");
+    /// assert_eq!(lossy[0].matched, "returns");
+    ///
+    /// let options = RosettaOptions { mode: ConversionMode::Lossless, ..Default::default() };
+    /// let (output, _, _) = RosettaStone::convert_with("f returns y", &options);
+    /// assert_eq!(output, "f returns y");
+    /// ```
+    pub fn lossy_substitutions(input: &str) -> Vec<Substitution> {
+        // Escapes and inline hints are explicit, whatever their phrase
+        let (protected, mut spans) = protect_escapes(input);
+        let protected = protect_hints(&protected, &mut spans, &mut Vec::new());
+        let protected = protect_bound_sets(&protected, &mut spans);
+        let mut skipped = Vec::new();
+        protect_lossy(&Self::sanitize(&protected), &mut spans, &mut skipped);
+        skipped
+    }
+
+    /// Check that every table symbol converts back to itself from its prose
+    ///
+    /// Each distinct `ROSETTA` symbol goes through `to_prose` and then
+    /// `convert`; symbols that come back as anything else are reported, in
+    /// table order. A symbol whose primary phrase is also another entry's
+    /// synonym is the usual failure.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// for issue in RosettaStone::audit_reverse_coverage() {
+    ///     eprintln!("{}", issue);
+    /// }
+    /// ```
+    pub fn audit_reverse_coverage() -> Vec<ReverseIssue> {
+        let mut seen = HashSet::new();
+        ROSETTA
+            .iter()
+            .filter(|entry| seen.insert(entry.symbol))
+            .filter_map(|entry| {
+                let prose = Self::to_prose(entry.symbol);
+                let (converted, _, _) = Self::convert(&prose);
+                (converted != entry.symbol).then_some(ReverseIssue {
+                    symbol: entry.symbol,
+                    prose,
+                    converted,
+                })
+            })
+            .collect()
+    }
+
+    /// Render every `ROSETTA` entry as a glossary: symbol, category, band, and synonyms
+    ///
+    /// Categories appear in table order, so the grouping follows the table's
+    /// band sections. Categories outside the banded glossary have no band.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{GlossaryFormat, RosettaStone};
+    ///
+    /// let markdown = RosettaStone::glossary(GlossaryFormat::Markdown);
+    /// assert!(markdown.contains("## quantifier (∀ Quantifiers[128-191])"));
+    /// assert!(markdown.contains("| `∀` | quantifier | Quantifiers[128-191] | for all, "));
+    ///
+    /// let json = RosettaStone::glossary(GlossaryFormat::Json);
+    /// assert!(json.contains(r#""symbol": "∀""#));
+    /// ```
+    pub fn glossary(format: GlossaryFormat) -> String {
+        let mut categories: Vec<&str> = Vec::new();
+        for entry in ROSETTA {
+            if !categories.contains(&entry.category) {
+                categories.push(entry.category);
+            }
+        }
+
+        let mut out = String::new();
+        match format {
+            GlossaryFormat::Markdown => {
+                for category in categories {
+                    let band = band_of_category(category);
+                    match band {
+                        Some(band) => {
+                            out.push_str(&format!("## {} ({} {})\n\n", category, band.glyph, band))
+                        }
+                        None => out.push_str(&format!("## {}\n\n", category)),
+                    }
+                    out.push_str("| Symbol | Category | Band | Synonyms |\n");
+                    out.push_str("|--------|----------|------|----------|\n");
+                    for entry in entries_by_category(category) {
+                        out.push_str(&format!(
+                            "| `{}` | {} | {} | {} |\n",
+                            entry.symbol.replace('|', "\\|"),
+                            category,
+                            band.map_or("-".to_string(), |band| band.to_string()),
+                            entry.patterns.join(", ").replace('|', "\\|")
+                        ));
+                    }
+                    out.push('\n');
+                }
+                out.truncate(out.trim_end().len());
+                out.push('\n');
+            }
+            GlossaryFormat::Json => {
+                let groups: Vec<String> = categories
+                    .into_iter()
+                    .map(|category| {
+                        let band = band_of_category(category).map_or("null".to_string(), |band| {
+                            format!(
+                                "{{\"glyph\": {}, \"name\": {}, \"start\": {}, \"end\": {}}}",
+                                json_string(band.glyph),
+                                json_string(band.name),
+                                band.start,
+                                band.end
+                            )
+                        });
+                        let entries: Vec<String> = entries_by_category(category)
+                            .into_iter()
+                            .map(|entry| {
+                                let synonyms: Vec<_> =
+                                    entry.patterns.iter().map(|p| json_string(p)).collect();
+                                format!(
+                                    "      {{\"symbol\": {}, \"synonyms\": [{}]}}",
+                                    json_string(entry.symbol),
+                                    synonyms.join(", ")
+                                )
+                            })
+                            .collect();
+                        format!(
+                            "  {{\n    \"category\": {},\n    \"band\": {},\n    \"entries\": [\n{}\n    ]\n  }}",
+                            json_string(category),
+                            band,
+                            entries.join(",\n")
+                        )
+                    })
+                    .collect();
+                out.push_str(&format!("[\n{}\n]\n", groups.join(",\n")));
+            }
+        }
+        out
+    }
+
+    /// Every phrase `convert` replaced in this input, as (matched_text, symbol, category)
+    ///
+    /// Unlike the static table, this lists only what fired, once per distinct
+    /// phrase and symbol, in the order substitutions were applied. Inline hints
+    /// are included.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// let legend = RosettaStone::conversion_legend("for all x in S");
+    /// assert!(legend.contains(&("for all".to_string(), "∀", "quantifier")));
+    /// assert!(legend.contains(&("in".to_string(), "∈", "set")));
+    /// ```
+    pub fn conversion_legend(input: &str) -> Vec<(String, &'static str, &'static str)> {
+        let (_, applied, _) = Self::run_pipeline(
+            input,
+            &RosettaOptions::default(),
+            ROSETTA,
+            &RegexEngine,
+            None,
+        );
+
+        let mut legend: Vec<(String, &'static str, &'static str)> = Vec::new();
+        for sub in applied {
+            let entry = (sub.matched, sub.symbol, sub.category);
+            if !legend.contains(&entry) {
+                legend.push(entry);
+            }
+        }
+        legend
+    }
+
+    /// Preview the substitutions `convert` would apply, without converting
+    /// Returned in application order, with spans into the original input
+    ///
+    /// Inline hints and the table step are previewed. The phrase passes run as
+    /// in `convert`, and table matches in text they rewrite are left out:
+    /// "x is between 1 and 10" becomes `1≤x≤10`, so its "and" is not planned.
+    /// `\aisp{...}` spans are skipped; a hint for a table symbol is
+    /// planned over the whole `[[symbol:phrase]]`, with the symbol as its pattern.
+    pub fn preview(input: &str) -> Vec<PlannedSubstitution> {
+        let mut planned: Vec<PlannedSubstitution> = Vec::new();
+        let mut claimed: Vec<Range<usize>> =
+            ESCAPED_SPAN.find_iter(input).map(|m| m.range()).collect();
+        // So are the set names of written `∀x∈S:` bindings
+        claimed.extend(
+            BOUND_SET
+                .captures_iter(input)
+                .filter_map(|caps| caps.name("set"))
+                .map(|m| m.range()),
+        );
+
+        // The text the table step sees, after the phrase passes
+        let mut stages = Vec::new();
+        Self::run_pipeline(
+            input,
+            &RosettaOptions::default(),
+            ROSETTA,
+            CompiledTable::builtin(),
+            Some(&mut stages),
+        );
+        let phrased = stages
+            .iter()
+            .rev()
+            .find(|stage| stage.stage == "signatures")
+            .map_or(input, |stage| stage.output.as_str());
+        let rewritten = rewritten_spans(input, phrased);
+        let overlaps = |claimed: &[Range<usize>], span: &Range<usize>| {
+            claimed
+                .iter()
+                .any(|c| c.start < span.end && span.start < c.end)
+        };
+
+        // Hints apply before the table, outside escaped spans
+        for caps in HINT.captures_iter(input) {
+            let span = caps.get(0).map_or(0..0, |m| m.range());
+            if overlaps(&claimed, &span) {
+                continue;
+            }
+            if let Some(entry) = ROSETTA.iter().find(|e| e.symbol == &caps[1]) {
+                planned.push(PlannedSubstitution {
+                    span: span.clone(),
+                    pattern: entry.symbol,
+                    symbol: entry.symbol,
+                });
+            }
+            claimed.push(span);
+        }
+
+        for (entry, i) in match_order(CompiledTable::builtin().entries()) {
+            for m in entry.regexes[i].find_iter(input) {
+                // Text claimed by an earlier substitution is already a symbol by
+                // now, and rewritten text no longer holds the phrase
+                if !overlaps(&claimed, &m.range()) && !overlaps(&rewritten, &m.range()) {
+                    claimed.push(m.range());
+                    planned.push(PlannedSubstitution {
+                        span: m.range(),
+                        pattern: entry.patterns[i],
+                        symbol: entry.symbol,
+                    });
+                }
+            }
+        }
+
+        planned
+    }
+
+    /// Suggest known patterns for unmapped words that look like typos
+    /// Returns (word, pattern) pairs within one edit; nothing is applied
+    #[cfg(feature = "fuzzy")]
+    pub fn fuzzy_candidates(unmapped: &[String]) -> Vec<(String, &'static str)> {
+        const MIN_WORD_LEN: usize = 5;
+
+        let mut candidates = Vec::new();
+        for word in unmapped
+            .iter()
+            .filter(|w| w.chars().count() >= MIN_WORD_LEN)
+        {
+            for entry in ROSETTA {
+                for pattern in entry.patterns.iter().filter(|p| !p.contains(' ')) {
+                    if word != pattern
+                        && within_one_edit(word, pattern)
+                        && !candidates.contains(&(word.clone(), *pattern))
+                    {
+                        candidates.push((word.clone(), *pattern));
+                    }
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Lowercased prose pattern → symbol lookup table
+    pub fn pattern_map() -> &'static HashMap<String, &'static str> {
+        &PATTERN_TO_SYMBOL
+    }
+
+    /// Symbol → primary prose pattern lookup table
+    pub fn symbol_map() -> &'static HashMap<&'static str, &'static str> {
+        &SYMBOL_TO_PATTERN
+    }
+
+    /// Build the lazily initialized tables now instead of on first use
+    ///
+    /// The compiled table, lookup maps, symbol automaton, and phrase-pass
+    /// patterns are otherwise built by the first conversion in a process, which
+    /// compiles a regex for every pattern in the table: on the order of 100ms in
+    /// a release build. Call this at startup in latency-sensitive services. It is
+    /// idempotent; later calls return immediately.
+    pub fn warmup() {
+        lazy_static::initialize(&LOSSLESS_TABLE);
+        lazy_static::initialize(&LOSSLESS_COMPILED);
+        lazy_static::initialize(&ROSETTA_SORTED);
+        lazy_static::initialize(&PATTERN_TO_SYMBOL);
+        lazy_static::initialize(&PATTERN_WORDS);
+        lazy_static::initialize(&CONCATENATED_PATTERNS);
+        lazy_static::initialize(&OPERATOR_SYMBOLS);
+        lazy_static::initialize(&SYMBOL_TO_CATEGORY);
+        lazy_static::initialize(&SYMBOL_TO_PATTERN);
+        lazy_static::initialize(&SYMBOL_EXPANSIONS);
+        lazy_static::initialize(&ROSETTA_COMPILED);
+        lazy_static::initialize(&NUMERAL);
+        lazy_static::initialize(&HINT);
+        lazy_static::initialize(&EXISTING_SYMBOLS);
+
+        // Each phrase pass compiles its own patterns on first use
+        let options = RosettaOptions {
+            symbolize_comparatives: true,
+            ..Default::default()
+        };
+        Self::convert_with("", &options);
+        let options = ProseOptions {
+            style: ProseStyle::Natural,
+            ..Default::default()
+        };
+        Self::to_prose_with("∀x∈S:x", &options);
+    }
+
+    /// Fold word-processor typography to ASCII before matching
+    ///
+    /// | Input | Output |
+    /// |-------|--------|
+    /// | ‘ ’ ‚ ′ (single quotes, prime) | `'` |
+    /// | “ ” „ ″ (double quotes, double prime) | `"` |
+    /// | ‐ ‑ ‒ – — ― (hyphens and dashes) | `-` |
+    /// | … (ellipsis) | `...` |
+    /// | no-break, figure, thin, and narrow no-break spaces | ` ` |
+    /// | zero-width space and joiners, soft hyphen | removed |
+    ///
+    /// The minus sign `−` is left alone: it is an AISP symbol. `convert` runs
+    /// this unless `RosettaOptions::preserve_typography` is set; escaped
+    /// `\aisp{...}` spans are never touched.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// assert_eq!(RosettaStone::sanitize("don’t use “x” – ever"), "don't use \"x\" - ever");
+    /// ```
+    pub fn sanitize(input: &str) -> String {
+        let mut result = String::with_capacity(input.len());
+        for c in input.chars() {
+            match c {
+                '\u{2018}' | '\u{2019}' | '\u{201A}' | '\u{2032}' => result.push('\''),
+                '\u{201C}' | '\u{201D}' | '\u{201E}' | '\u{2033}' => result.push('"'),
+                '\u{2010}'..='\u{2015}' => result.push('-'),
+                '\u{2026}' => result.push_str("..."),
+                '\u{00A0}' | '\u{2007}' | '\u{2009}' | '\u{202F}' => result.push(' '),
+                '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{00AD}' => {}
+                _ => result.push(c),
+            }
+        }
+        result
+    }
+
+    /// Split multi-word patterns written without spaces back into their words
+    /// "x greaterthan y" → "x greater than y", "a ifandonlyif b" → "a if and only if b"
+    ///
+    /// Only concatenations of known patterns are split, and only whole words.
+    /// Concatenations under five letters ("isa", "isin") read as words of their
+    /// own and are left alone.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// assert_eq!(RosettaStone::split_concatenated("x notequal y"), "x not equal y");
+    /// assert_eq!(RosettaStone::split_concatenated("isa forallx"), "isa forallx");
+    /// ```
+    pub fn split_concatenated(input: &str) -> String {
+        let (regex, patterns) = &*CONCATENATED_PATTERNS;
+        regex
+            .replace_all(input, |caps: &regex::Captures| {
+                patterns[&caps[0].to_lowercase()].to_string()
+            })
+            .to_string()
+    }
+
+    /// Write spelled-out numbers below a thousand as digits
+    /// "three users" → "3 users", "twenty-one" → "21", "the first element" → "the 1st element"
+    ///
+    /// Numbers inside a table pattern ("exactly one") and the "one" of "at
+    /// least one" are left for the converter. "one" after a determiner ("no one", "each one") is a
+    /// pronoun, and an ordinal only becomes "1st" when a word follows it and
+    /// it does not open a sentence or follow "a" or a number ("First, ...",
+    /// "a second", "five second timeout").
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// assert_eq!(
+    ///     RosettaStone::normalize_numerals("the first of one hundred and five users"),
+    ///     "the 1st of 105 users"
+    /// );
+    /// assert_eq!(RosettaStone::normalize_numerals("exactly one user"), "exactly one user");
+    /// ```
+    pub fn normalize_numerals(input: &str) -> String {
+        const PRONOUN_DETERMINERS: &[&str] = &[
+            "no", "any", "each", "every", "some", "which", "this", "that", "the",
+        ];
+        const ORDINAL_BLOCKERS: &[&str] = &["a", "an"];
+        lazy_static! {
+            /// "at least" ending the text before a "one" ("at least one" is `∃`)
+            static ref AT_LEAST: Regex = Regex::new(r"(?i)\bat\s+least$").unwrap();
+        }
+
+        let claimed: Vec<_> = Self::preview(input)
+            .into_iter()
+            .filter(|planned| NUMERAL.is_match(planned.pattern))
+            .map(|planned| planned.span)
+            .collect();
+        let mut result = String::with_capacity(input.len());
+        let mut last = 0;
+
+        for m in NUMERAL.find_iter(input) {
+            let before = input[..m.start()].trim_end();
+            let previous = before
+                .rsplit(|c: char| !c.is_alphanumeric())
+                .next()
+                .unwrap_or("")
+                .to_lowercase();
+            let opens_sentence = before.is_empty() || before.ends_with(['.', '!', '?', ':', '\n']);
+            let followed_by_word = input[m.end()..]
+                .strip_prefix(|c: char| c == ' ')
+                .is_some_and(|rest| rest.starts_with(char::is_alphabetic));
+
+            let (value, ordinal) = numeral_value(m.as_str());
+            let keep = claimed
+                .iter()
+                .any(|span| span.start < m.end() && m.start() < span.end)
+                || (m.as_str().eq_ignore_ascii_case("one")
+                    && (PRONOUN_DETERMINERS.contains(&previous.as_str())
+                        || AT_LEAST.is_match(before)))
+                || (ordinal
+                    && (!followed_by_word
+                        || opens_sentence
+                        || ORDINAL_BLOCKERS.contains(&previous.as_str())
+                        || previous.starts_with(|c: char| c.is_ascii_digit())
+                        || NUMERAL.is_match(&previous)));
+            if keep {
+                continue;
+            }
+
+            result.push_str(&input[last..m.start()]);
+            result.push_str(&value.to_string());
+            if ordinal {
+                result.push_str(ordinal_suffix(value));
+            }
+            last = m.end();
+        }
+        result.push_str(&input[last..]);
+        result
+    }
+
+    /// Rewrite plural words to their singular form when the plural is not itself a pattern
+    /// "booleans" → "boolean", "complex numbers" → "complex number"; "access" is left alone
+    pub fn singularize(input: &str) -> String {
+        const MIN_SINGULAR_LEN: usize = 3;
+        lazy_static! {
+            static ref PLURAL: Regex = Regex::new(r"\b[A-Za-z]+s\b").unwrap();
+        }
+
+        PLURAL
+            .replace_all(input, |caps: &regex::Captures| {
+                let word = &caps[0];
+                if PATTERN_TO_SYMBOL.contains_key(&word.to_lowercase()) {
+                    return word.to_string();
+                }
+
+                [
+                    &word[..word.len() - 1],
+                    word.strip_suffix("es").unwrap_or(""),
+                ]
+                .into_iter()
+                .find(|singular| {
+                    singular.len() >= MIN_SINGULAR_LEN
+                        && PATTERN_WORDS.contains(&singular.to_lowercase())
+                })
+                .unwrap_or(word)
+                .to_string()
+            })
+            .to_string()
+    }
+
+    /// Check that a custom entry can be matched and expanded reliably
+    ///
+    /// Rejects empty symbols, symbols containing whitespace or `$`, and empty
+    /// or duplicate patterns. Patterns that contain each other within one entry
+    /// are fine: the longer one is always tried first.
+    pub fn validate_entry(entry: &RosettaEntry) -> Result<(), EntryError> {
+        let symbol = entry.symbol.to_string();
+        if entry.symbol.is_empty() {
+            return Err(EntryError::EmptySymbol);
+        }
+        if entry.symbol.contains(char::is_whitespace) {
+            return Err(EntryError::WhitespaceInSymbol { symbol });
+        }
+        if entry.symbol.contains('$') {
+            return Err(EntryError::DollarInSymbol { symbol });
+        }
+
+        for (i, pattern) in entry.patterns.iter().enumerate() {
+            if pattern.trim().is_empty() {
+                return Err(EntryError::EmptyPattern { symbol });
+            }
+            if entry.patterns[..i]
+                .iter()
+                .any(|earlier| earlier.eq_ignore_ascii_case(pattern))
+            {
+                return Err(EntryError::DuplicatePattern {
+                    symbol,
+                    pattern: pattern.to_string(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check every entry of a custom table, then look for cross-entry shadowing
+    ///
+    /// A pattern shadows another entry's pattern when it matches inside it and
+    /// is applied first, so the longer phrase never converts as a whole.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{EntryError, RosettaEntry, RosettaStone};
+    ///
+    /// static TABLE: &[RosettaEntry] = &[
+    ///     RosettaEntry { symbol: "∧", patterns: &["and", "together with"], category: "logic" },
+    ///     RosettaEntry { symbol: "↔", patterns: &["to and fro"], category: "logic" },
+    /// ];
+    /// assert!(matches!(
+    ///     RosettaStone::validate_table(TABLE),
+    ///     Err(EntryError::ShadowedPattern { .. })
+    /// ));
+    /// ```
+    pub fn validate_table(entries: &[RosettaEntry]) -> Result<(), EntryError> {
+        for entry in entries {
+            Self::validate_entry(entry)?;
+        }
+
+        let compiled = compile_entries(&sort_entries(entries));
+        let order = match_order(&compiled);
+        for (i, (entry, p)) in order.iter().enumerate() {
+            for (later, q) in &order[i + 1..] {
+                if later.symbol != entry.symbol && entry.regexes[*p].is_match(later.patterns[*q]) {
+                    return Err(EntryError::ShadowedPattern {
+                        symbol: entry.symbol.to_string(),
+                        pattern: entry.patterns[*p].to_string(),
+                        shadowed_symbol: later.symbol.to_string(),
+                        shadowed_pattern: later.patterns[*q].to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parse a table from JSON: an array of `{"symbol", "patterns", "category"}` objects
+    ///
+    /// The entries are not validated; compile the converted entries with
+    /// `CompiledTable::try_new`, which runs `validate_table` on them.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::{CompiledTable, RosettaStone};
+    ///
+    /// let json = r#"[{"symbol": "⊕", "patterns": ["xor"], "category": "logic"}]"#;
+    /// let entries: Vec<_> = RosettaStone::load_table(json)
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|entry| entry.into_static())
+    ///     .collect();
+    /// let table = CompiledTable::try_new(&entries).unwrap();
+    /// let (aisp, _, _) = RosettaStone::convert_with_table("a xor b", Some(&table));
+    /// assert_eq!(aisp, "a ⊕ b");
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn load_table(json: &str) -> Result<Vec<OwnedRosettaEntry>, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Split AISP into a stream of symbol and identifier tokens
+    /// Known multi-character symbols ("∃!", "⟦Ω⟧") stay whole; whitespace is dropped
+    pub fn tokenize(aisp: &str) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut rest = aisp;
+
+        while let Some(c) = rest.chars().next() {
+            let len = if c.is_whitespace() {
+                rest = &rest[c.len_utf8()..];
+                continue;
+            } else if c.is_alphanumeric() || c == '_' {
+                rest.find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .unwrap_or(rest.len())
+            } else {
+                OPERATOR_SYMBOLS
+                    .iter()
+                    .find(|s| rest.starts_with(*s))
+                    .map_or(c.len_utf8(), |s| s.len())
+            };
+
+            tokens.push(rest[..len].to_string());
+            rest = &rest[len..];
+        }
+
+        tokens
+    }
+
+    /// Split prose into sentences, each keeping its terminator
+    /// Abbreviations ("e.g.", "etc.") and decimals ("0.5") do not end a sentence
+    pub fn split_sentences(prose: &str) -> Vec<&str> {
+        const ABBREVIATIONS: &[&str] = &[
+            "e.g", "i.e", "etc", "vs", "cf", "approx", "dr", "mr", "mrs", "ms", "no", "fig",
+        ];
+
+        lazy_static! {
+            static ref TERMINATOR: Regex = Regex::new(r"[.!?]+(?:[ \t]+|$)|\n+").unwrap();
+        }
+        let mut sentences = Vec::new();
+        let mut start = 0;
+
+        for m in TERMINATOR.find_iter(prose) {
+            let before = &prose[start..m.start()];
+            let last_word = before.split_whitespace().last().unwrap_or("");
+            let abbreviated = m.as_str().trim_end() == "."
+                && (ABBREVIATIONS.contains(&last_word.to_lowercase().as_str())
+                    || (last_word.len() == 1 && last_word.chars().all(char::is_uppercase)));
+            if abbreviated {
+                continue;
+            }
+
+            let sentence = prose[start..m.end()].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence);
+            }
+            start = m.end();
+        }
+
+        let rest = prose[start..].trim();
+        if !rest.is_empty() {
+            sentences.push(rest);
+        }
+        sentences
+    }
+
+    /// Heuristically detect text that is already AISP
+    /// True for documents (`𝔸` header or `⟦…⟧` blocks) and for text where at least
+    /// 30% of tokens are operator symbols ("∀ x∈S", "x≥5")
+    pub fn is_aisp(text: &str) -> bool {
+        const MIN_SYMBOL_SHARE: f64 = 0.3;
+
+        let trimmed = text.trim_start();
+        if trimmed.starts_with('𝔸') || trimmed.contains('⟦') {
+            return true;
+        }
+
+        Self::symbol_share(text) >= MIN_SYMBOL_SHARE
+    }
+
+    /// Share of `tokenize` tokens that are non-word table symbols (0.0 - 1.0)
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// assert_eq!(RosettaStone::symbol_share("∀ users must authenticate"), 0.25);
+    /// assert_eq!(RosettaStone::symbol_share("plain prose"), 0.0);
+    /// ```
+    pub fn symbol_share(text: &str) -> f64 {
+        let tokens = Self::tokenize(text);
+        if tokens.is_empty() {
+            return 0.0;
+        }
+        let symbols = tokens
+            .iter()
+            .filter(|t| OPERATOR_SYMBOLS.contains(&t.as_str()))
+            .count();
+        symbols as f64 / tokens.len() as f64
+    }
+
+    /// Calculate conversion confidence
+    pub fn confidence(input_len: usize, mapped_chars: usize) -> f64 {
+        if input_len == 0 {
+            return 1.0;
+        }
+        (mapped_chars as f64 / input_len as f64).min(1.0)
+    }
+
+    /// Specificity weight of a pattern, from 0.5 to 2.0
+    ///
+    /// Single words under four letters ("in", "or") weigh 0.5, operators and
+    /// other single words 1.0, and each extra word in a phrase adds 0.5, up to
+    /// 2.0 ("if and only if").
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// assert_eq!(RosettaStone::pattern_specificity("in"), 0.5);
+    /// assert_eq!(RosettaStone::pattern_specificity("implies"), 1.0);
+    /// assert_eq!(RosettaStone::pattern_specificity("if and only if"), 2.0);
+    /// ```
+    pub fn pattern_specificity(pattern: &str) -> f64 {
+        let words = pattern.split_whitespace().count();
+        if words > 1 {
+            (1.0 + 0.5 * (words - 1) as f64).min(2.0)
+        } else if pattern.chars().any(char::is_alphabetic) && pattern.chars().count() < 4 {
+            0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Calculate conversion confidence with each match weighted by its pattern's specificity
+    ///
+    /// Inline hints weigh 2.0, as explicit as a match can be. Short common words
+    /// count for less than in `confidence`, long phrases for more.
+    pub fn weighted_confidence(input_len: usize, substitutions: &[Substitution]) -> f64 {
+        if input_len == 0 {
+            return 1.0;
+        }
+        let weighted: f64 = substitutions
+            .iter()
+            .map(|sub| {
+                let weight = if sub.pattern == sub.symbol {
+                    2.0
+                } else {
+                    Self::pattern_specificity(sub.pattern)
+                };
+                sub.matched.len() as f64 * weight
+            })
+            .sum();
+        (weighted / input_len as f64).min(1.0)
+    }
+
+    /// Calculate each category's contribution to conversion confidence
+    /// Values sum to the overall `confidence` score
+    pub fn category_confidence(
+        input_len: usize,
+        category_chars: &HashMap<&'static str, usize>,
+    ) -> HashMap<String, f64> {
+        category_chars
+            .iter()
+            .map(|(category, chars)| (category.to_string(), Self::confidence(input_len, *chars)))
+            .collect()
+    }
+
+    /// List unmapped words by their contribution to the confidence deficit
+    /// Returns (word, unmapped_chars) pairs, largest contribution first
+    pub fn coverage_gaps(input: &str) -> Vec<(String, usize)> {
+        let (result, _, unmapped) = Self::convert(input);
+
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        for m in WORD.find_iter(&result) {
+            *counts.entry(m.as_str().to_lowercase()).or_insert(0) += 1;
+        }
+
+        let mut gaps: Vec<_> = unmapped
+            .into_iter()
+            .map(|word| {
+                let chars = word.len() * counts.get(&word).copied().unwrap_or(0);
+                (word, chars)
+            })
+            .collect();
+
+        gaps.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        gaps
+    }
+
+    /// Rank the words a corpus leaves unmapped as candidates for custom mappings
+    ///
+    /// Spellings that share a stem ("cache", "caches", "caching") are grouped
+    /// into one suggestion. Suggestions are ordered by
+    /// occurrences, then by the number of documents, then alphabetically.
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// let corpus = ["the server caches x", "the server is caching y", "widget"];
+    /// let suggestions = RosettaStone::suggest_mappings(&corpus);
+    /// assert_eq!(suggestions[0].word, "caches");
+    /// assert_eq!(suggestions[0].variants, vec!["caching"]);
+    /// assert_eq!(suggestions[1].word, "server");
+    /// assert_eq!(suggestions[1].documents, 2);
+    /// ```
+    pub fn suggest_mappings(corpus: &[&str]) -> Vec<MappingSuggestion> {
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+        let mut documents: HashMap<String, HashSet<usize>> = HashMap::new();
+
+        for (index, document) in corpus.iter().enumerate() {
+            let (result, _, unmapped) = Self::convert(document);
+            let unmapped: HashSet<String> = unmapped.into_iter().collect();
+            for m in WORD.find_iter(&result) {
+                let word = m.as_str().to_lowercase();
+                if unmapped.contains(&word) {
+                    *occurrences.entry(word.clone()).or_insert(0) += 1;
+                    documents.entry(word).or_default().insert(index);
+                }
+            }
+        }
+
+        let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+        for word in occurrences.keys() {
+            groups
+                .entry(Self::word_stem(word).to_string())
+                .or_default()
+                .push(word.clone());
+        }
+
+        let mut suggestions: Vec<_> = groups
+            .into_values()
+            .map(|mut words| {
+                words.sort_by(|a, b| occurrences[b].cmp(&occurrences[a]).then_with(|| a.cmp(b)));
+                let docs: HashSet<usize> =
+                    words.iter().flat_map(|w| &documents[w]).copied().collect();
+                MappingSuggestion {
+                    occurrences: words.iter().map(|w| occurrences[w]).sum(),
+                    documents: docs.len(),
+                    word: words.remove(0),
+                    variants: words,
+                }
+            })
+            .collect();
+
+        suggestions.sort_by(|a, b| {
+            b.occurrences
+                .cmp(&a.occurrences)
+                .then_with(|| b.documents.cmp(&a.documents))
+                .then_with(|| a.word.cmp(&b.word))
+        });
+        suggestions
+    }
+
+    /// Strip one common inflection and a doubled final letter:
+    /// "caching" → "cach", "logging" → "log"
+    fn word_stem(word: &str) -> &str {
+        let suffixes = [
+            "ions", "ion", "ings", "ing", "ers", "er", "ed", "es", "s", "e",
+        ];
+        let stem = suffixes
+            .iter()
+            .filter_map(|suffix| word.strip_suffix(suffix))
+            .find(|stem| stem.len() >= 3)
+            .unwrap_or(word);
+        match stem.as_bytes() {
+            [.., a, b] if a == b && stem.len() > 3 => &stem[..stem.len() - 1],
+            _ => stem,
+        }
+    }
+
+    /// Clean up operators by removing extra spaces
+    fn cleanup_operators(input: &str) -> String {
+        lazy_static! {
+            /// Each operator with the whitespace around it
+            static ref SPACED_OPERATORS: Vec<(Regex, &'static str)> =
+                [
+                    "≜", "≔", "⊑", "↦", "⇒", "∣", "∈", "→", "⊢", "⇔", "∧", "∨", "≥", "≤", "≡", "≢",
+                ]
+                    .into_iter()
+                    .map(|op| {
+                        let regex = Regex::new(&format!(r"\s*{}\s*", escape_regex(op))).unwrap();
+                        (regex, op)
+                    })
+                    .collect();
+            static ref GUARDED: Regex = Regex::new(r"([∀∃∄]!?)\s+([^\s∣:;,]+∣)").unwrap();
+            static ref NEGATED_QUANTIFIER: Regex =
+                Regex::new(r"(?i)(¬∀|∄)\s+(?:(?:a|an|the)\s+)?([^\s∣:;,])").unwrap();
+            static ref NEGATION: Regex = Regex::new(r"¬\s+([^\s∀∃∄])").unwrap();
+            static ref PERCENTAGE: Regex = Regex::new(r"([≥≤<>≈≡≢])\s+(\d+(?:\.\d+)?%)").unwrap();
+        }
+        let mut result = input.to_string();
+
+        for (regex, op) in SPACED_OPERATORS.iter() {
+            result = regex.replace_all(&result, *op).to_string();
+        }
+
+        // A quantifier binds directly to the condition it guards: "∀ x∈S∣P" → "∀x∈S∣P"
+        result = GUARDED.replace_all(&result, "$1$2").to_string();
+
+        // A negated quantifier binds to its variable: "∄ a solution" → "∄solution"
+        result = NEGATED_QUANTIFIER.replace_all(&result, "$1$2").to_string();
+
+        // Negation binds to its operand: "the user ¬ login" → "the user ¬login"
+        result = NEGATION.replace_all(&result, "¬$1").to_string();
+
+        // A comparison binds to the percentage it bounds: "≥ 80%" → "≥80%"
+        PERCENTAGE.replace_all(&result, "$1$2").to_string()
+    }
+
+    /// Convert exponent notation to superscripts
+    /// "R^3" → "ℝ³", "x^n" → "xⁿ", "x to the power of 4" → "x⁴", "x to the 3rd power" → "x³"
+    ///
+    /// Exponents without a superscript form use a caret: "x to the power of k" → "x^k".
+    fn convert_exponents(input: &str) -> String {
+        lazy_static! {
+            static ref POWER: Regex = Regex::new(
+                r"(?i)(?:\s+raised)?\s+to\s+the\s+(?:power\s+of\s+(\d+|n)|(\d+|n)(?:st|nd|rd|th)?\s+power)\b",
+            )
+            .unwrap();
+            static ref VARIABLE_POWER: Regex =
+                Regex::new(r"(?i)(?:\s+raised)?\s+to\s+the\s+power\s+of\s+([A-Za-z_]\w*)").unwrap();
+            static ref CARET: Regex = Regex::new(r"\b([A-Za-z]\w*)\^(\d+|n)\b").unwrap();
+        }
+        let result = POWER.replace_all(input, |caps: &regex::Captures| {
+            let exponent = caps.get(1).or(caps.get(2)).map_or("", |m| m.as_str());
+            to_superscript(&exponent.to_lowercase())
+        });
+
+        let result = VARIABLE_POWER.replace_all(&result, "^$1");
+
+        CARET
+            .replace_all(&result, |caps: &regex::Captures| {
+                // Single-letter number sets: N, Z, Q, R, C
+                let base = match &caps[1] {
+                    "N" => "ℕ",
+                    "Z" => "ℤ",
+                    "Q" => "ℚ",
+                    "R" => "ℝ",
+                    "C" => "ℂ",
+                    other => other,
+                };
+                format!("{}{}", base, to_superscript(&caps[2]))
+            })
+            .to_string()
+    }
+
+    /// Rewrite adjectival comparisons as a comparison of attributes
+    /// "A is more secure than B" → "secure(A)>secure(B)", "the cache is less
+    /// reliable than the disk" → "reliable(cache)<reliable(disk)"
+    ///
+    /// Numeric comparisons ("more than 5") have no adjective and are left to the table.
+    fn convert_comparatives(input: &str) -> String {
+        lazy_static! {
+            static ref COMPARATIVE: Regex = Regex::new(
+                r"(?i)\b(?:the\s+)?([A-Za-z_]\w*)\s+(?:is|are)\s+(more|less)\s+([a-z]+)\s+than\s+(?:the\s+)?([A-Za-z_]\w*)\b",
+            )
+            .unwrap();
+        }
+
+        COMPARATIVE
+            .replace_all(input, |caps: &regex::Captures| {
+                let adjective = &caps[3];
+                if PATTERN_TO_SYMBOL.contains_key(&adjective.to_lowercase()) {
+                    return caps[0].to_string();
+                }
+                let op = if caps[2].eq_ignore_ascii_case("more") {
+                    ">"
+                } else {
+                    "<"
+                };
+                format!("{adjective}({}){op}{adjective}({})", &caps[1], &caps[4])
+            })
+            .to_string()
+    }
+
+    /// Rewrite absolute values and factorials
+    /// "the absolute value of x" → "|x|", "n factorial" → "n!", "the factorial of 5" → "5!"
+    fn convert_arithmetic(input: &str) -> String {
+        lazy_static! {
+            static ref ABSOLUTE: Regex = Regex::new(
+                r"(?i)\b(?:the\s+)?absolute\s+value\s+of\s+([A-Za-z_]\w*|\d+(?:\.\d+)?)\b"
+            )
+            .unwrap();
+            static ref FACTORIAL_OF: Regex =
+                Regex::new(r"(?i)\b(?:the\s+)?factorial\s+of\s+([A-Za-z_]\w*|\d+(?:\.\d+)?)\b")
+                    .unwrap();
+            static ref FACTORIAL: Regex =
+                Regex::new(r"(?i)\b([A-Za-z_]\w*|\d+(?:\.\d+)?)\s+factorial\b").unwrap();
+        }
+
+        let result = ABSOLUTE.replace_all(input, "|$1|");
+        let result = FACTORIAL_OF.replace_all(&result, "$1!");
+        FACTORIAL.replace_all(&result, "$1!").to_string()
+    }
+
+    /// Convert the condition a quantifier or probability is guarded by
+    /// "∀ x∈S such that x > 0" → "∀x∈S∣x > 0", "P(A given B)" → "P(A∣B)"
+    ///
+    /// Elsewhere "where" and "given" stay prose: "the table where users are kept".
+    fn convert_conditions(input: &str) -> String {
+        lazy_static! {
+            static ref GUARDED: Regex =
+                Regex::new(r"([∀∃∄]!?)[ \t]*([^\s∣:;,]+)\s+(?i:such\s+that|where|given)\s+")
+                    .unwrap();
+            static ref GIVEN: Regex =
+                Regex::new(r"\bP\(([^()∣\n]+?)\s+(?i:given|conditioned\s+on)\s+([^()∣\n]+)\)")
+                    .unwrap();
+        }
+        let result = GUARDED.replace_all(input, "$1$2∣");
+        GIVEN.replace_all(&result, "P($1∣$2)").to_string()
+    }
+
+    /// Convert if/then/else into guarded branches
+    /// "if x⇒y else z" → "x⇒y∣¬x⇒z", "if x>5⇒y else z" → "x>5⇒y∣¬(x>5)⇒z"
+    ///
+    /// "else" is only a keyword here; on its own it stays prose.
+    fn convert_conditionals(input: &str) -> String {
+        lazy_static! {
+            static ref CONDITIONAL: Regex = Regex::new(
+                r"(?i)\bif\s+([^⇒∣\n]+?)\s*⇒\s*([^⇒∣\n]+?)\s*\belse\b\s*([^⇒∣\n.;]+)",
+            )
+            .unwrap();
+        }
+
+        CONDITIONAL
+            .replace_all(input, |caps: &regex::Captures| {
+                let condition = caps[1].trim();
+                let then_branch = caps[2].trim_end_matches(',').trim();
+                let else_branch = caps[3].trim();
+                let negated = if condition.chars().all(|c| c.is_alphanumeric() || c == '_')
+                    || Self::is_grouped(condition)
+                {
+                    format!("¬{}", condition)
+                } else {
+                    format!("¬({})", condition)
+                };
+                format!("{}⇒{}∣{}⇒{}", condition, then_branch, negated, else_branch)
+            })
+            .to_string()
+    }
+
+    /// Rewrite set-builder phrases into braces, leaving the predicate for the Rosetta pass
+    /// "the set of all x such that x is positive" → "{x ∣ x is positive}"
+    fn convert_set_builders(input: &str) -> String {
+        lazy_static! {
+            static ref BUILDER: Regex = Regex::new(
+                r"(?i)\b(?:the\s+)?set\s+of\s+all\s+(\w+(?:\s+in\s+\w+)?)\s*(?:such\s+that|where|\|)\s*(.+?)\s*([,;\n]|\.(?:\s|$)|$)",
+            )
+            .unwrap();
+        }
+        BUILDER.replace_all(input, "{$1 ∣ $2}$3").to_string()
+    }
+
+    /// Rewrite cardinality phrases into bars
+    /// "the number of elements in S" → "|S|", "the size of the file" → "|file|"
+    ///
+    /// A determiner before the collection is dropped, never bracketed.
+    fn convert_cardinality(input: &str) -> String {
+        const DETERMINERS: &str = "the|a|an|this|that|these|those|all";
+        lazy_static! {
+            static ref CARDINALITY: Regex = Regex::new(&format!(
+                r"(?i)\b(?:the\s+)?(?:number|count|cardinality|size)\s+of\s+(?:(?:elements|members|items)\s+(?:in|of)\s+)?(?:(?:{DETERMINERS})\s+)?([a-z_]\w*)\b",
+            ))
+            .unwrap();
+        }
+        CARDINALITY
+            .replace_all(input, |caps: &regex::Captures| {
+                let determiner = DETERMINERS
+                    .split('|')
+                    .any(|d| d.eq_ignore_ascii_case(&caps[1]));
+                if determiner {
+                    caps[0].to_string()
+                } else {
+                    format!("|{}|", &caps[1])
+                }
+            })
+            .to_string()
+    }
+
+    /// Rewrite percentages and ratios into compact notation
+    /// "80 percent" → "80%", "3 out of 4" → "3/4", "the ratio of a to b" → "a/b"
+    ///
+    /// Ratios take the fraction form: a bare "a:b" reads back the same as a clock time.
+    fn convert_quantities(input: &str) -> String {
+        lazy_static! {
+            static ref PERCENT: Regex =
+                Regex::new(r"(?i)\b(\d+(?:\.\d+)?)\s*(?:percent|per\s+cent|pct)\b").unwrap();
+            static ref OUT_OF: Regex = Regex::new(r"(?i)\b(\w+)\s+out\s+of\s+(\w+)\b").unwrap();
+            static ref RATIO: Regex =
+                Regex::new(r"(?i)\b(?:the\s+)?ratio\s+of\s+(\w+)\s+to\s+(\w+)\b").unwrap();
+        }
+        let result = PERCENT.replace_all(input, "$1%");
+
+        // Only numbers read as a fraction; "x out of range" stays prose
+        let result = OUT_OF.replace_all(&result, |caps: &regex::Captures| {
+            let number = |word: &str| -> Option<String> {
+                if word.chars().all(|c| c.is_ascii_digit()) {
+                    Some(word.to_string())
+                } else {
+                    number_word(word).map(str::to_string)
+                }
+            };
+            match (number(&caps[1]), number(&caps[2])) {
+                (Some(part), Some(whole)) => format!("{}/{}", part, whole),
+                _ => caps[0].to_string(),
+            }
+        });
+
+        RATIO.replace_all(&result, "$1/$2").to_string()
+    }
+
+    /// Rewrite counting quantifiers bound to a predicate
+    /// "at least two of the checks pass" → "∃≥2 checks:pass", "exactly three users
+    /// are admins" → "∃=3 users:are admins", "at most one of the jobs fails" → "∃≤1 jobs:fails"
+    ///
+    /// The predicate runs to the end of the clause (punctuation or "then").
+    /// Without "of", the phrase must open a clause, so "x is at least 5 ms" stays
+    /// a comparison. Counts of one use the table's symbols: "at least one" is
+    /// `∃` and "exactly one" is `∃!`. Any other "at least one" ("there is at
+    /// least one x in S") is claimed as `∃` here, before "at least" becomes `≥`.
+    fn convert_counting_quantifiers(input: &str) -> String {
+        lazy_static! {
+            static ref COUNTING: Regex = Regex::new(
+                r"(?i)(?P<lead>^|[.;:,(]\s*|\b(?:if|when|then|and|or)\s+)?\b(?P<bound>at\s+least|at\s+most|exactly)\s+(?P<count>\d+|[A-Za-z]+)\s+(?:(?P<of>of)\s+(?:the\s+)?)?(?P<set>[A-Za-z_]\w*)\s+(?P<body>[^.;,\n]+?)(?P<end>\s+then\b|[.;,\n]|$)",
+            )
+            .unwrap();
+            /// "at least one" outside a counted clause: plain existence
+            static ref AT_LEAST_ONE: Regex =
+                Regex::new(r"(?i)\b(?:there\s+(?:is|are|exists?)\s+)?at\s+least\s+one\b").unwrap();
+        }
+
+        let result = COUNTING.replace_all(input, |caps: &regex::Captures| {
+            let count = caps["count"]
+                .parse::<u32>()
+                .ok()
+                .map(|n| n.to_string())
+                .or_else(|| number_word(&caps["count"]).map(str::to_string));
+            let Some(count) = count else {
+                return caps[0].to_string();
+            };
+            if caps.name("of").is_none() && caps.name("lead").is_none() {
+                return caps[0].to_string();
+            }
+
+            let bound = caps["bound"].to_lowercase();
+            let quantifier = match (bound.split_whitespace().last(), count.as_str()) {
+                (Some("least"), "1") => "∃".to_string(),
+                (Some("exactly"), "1") => "∃!".to_string(),
+                (Some("least"), _) => format!("∃≥{}", count),
+                (Some("most"), _) => format!("∃≤{}", count),
+                _ => format!("∃={}", count),
+            };
+            format!(
+                "{}{} {}:{}{}",
+                caps.name("lead").map_or("", |m| m.as_str()),
+                quantifier,
+                &caps["set"],
+                &caps["body"],
+                &caps["end"]
+            )
+        });
+
+        // Claim "at least one" before "at least" becomes ≥
+        AT_LEAST_ONE.replace_all(&result, "∃").to_string()
+    }
+
+    /// Rewrite probability and expectation phrases into function notation
+    /// "the probability of X given Y" → "P(X∣Y)", "P of X conditioned on Y" → "P(X∣Y)",
+    /// "expected value of X" → "E[X]",
+    /// "variance of X" → "Var(X)", "standard deviation of X" → "σ(X)"
+    fn convert_statistics(input: &str) -> String {
+        lazy_static! {
+            static ref PROBABILITY: Regex = Regex::new(&format!(
+                r"(?i)\b(?:(?:the\s+)?probability|(?-i:P))\s+(?:of|that)\s+({operand})(?:\s+(?:given|conditioned\s+on)\s+({operand}))?",
+                operand = STATISTIC_OPERAND
+            ))
+            .unwrap();
+            static ref EXPECTATION: Regex = Regex::new(&format!(
+                r"(?i)\b(?:the\s+)?(?:expected\s+value|expectation)\s+of\s+({operand})",
+                operand = STATISTIC_OPERAND
+            ))
+            .unwrap();
+            static ref SPREAD: Regex = Regex::new(&format!(
+                r"(?i)\b(?:the\s+)?(variance|standard\s+deviation|std\s+dev)\s+of\s+({operand})",
+                operand = STATISTIC_OPERAND
+            ))
+            .unwrap();
+        }
+        const STATISTIC_OPERAND: &str = r"[A-Za-z_]\w*(?:\s*=\s*\w+)?";
+        let tight = |operand: &str| operand.split_whitespace().collect::<String>();
+
+        let result = PROBABILITY.replace_all(input, |caps: &regex::Captures| match caps.get(2) {
+            Some(given) => format!("P({}∣{})", tight(&caps[1]), tight(given.as_str())),
+            None => format!("P({})", tight(&caps[1])),
+        });
+
+        let result = EXPECTATION.replace_all(&result, |caps: &regex::Captures| {
+            format!("E[{}]", tight(&caps[1]))
+        });
+
+        SPREAD
+            .replace_all(&result, |caps: &regex::Captures| {
+                let symbol = if caps[1].eq_ignore_ascii_case("variance") {
+                    "Var"
+                } else {
+                    "σ"
+                };
+                format!("{}({})", symbol, tight(&caps[2]))
+            })
+            .to_string()
+    }
+
+    /// Rewrite "between X and Y" ranges into chained comparisons or intervals
+    /// "a value between 1 and 100" → "1≤value≤100", "pick between 1 and 10" → "pick [1,10]"
+    ///
+    /// Only numeric bounds form a range; "between users and admins" stays prose.
+    fn convert_ranges(input: &str) -> String {
+        lazy_static! {
+            static ref RANGE: Regex = Regex::new(&format!(
+                r"(?i)\b(?:(?P<article>the|a|an)\s+)?(?:(?P<subject>[A-Za-z_]\w*)\s+(?:(?:must|should|shall|will|can)\s+)?(?P<verb>(?:be|is|are|lies|lie|falls|fall)\s+)?)?(?P<between>between)\s+(?P<low>{bound})\s+and\s+(?P<high>{bound})(?:\s?(?P<unit>%|(?:ms|s|sec|min|h|hr|kb|mb|gb|tb|px|m|km|kg|g)\b))?(?:,?\s+(?P<ends>inclusive|exclusive)\b)?",
+                bound = r"-?\d+(?:\.\d+)?|[A-Za-z]+"
+            ))
+            .unwrap();
+        }
+        let connectives = [
+            "must", "should", "shall", "will", "can", "be", "is", "are", "lies", "lie", "falls",
+            "fall", "and", "or", "not",
+        ];
+
+        RANGE
+            .replace_all(input, |caps: &regex::Captures| {
+                let number = |word: &str| -> Option<String> {
+                    if word.parse::<f64>().is_ok() {
+                        Some(word.to_string())
+                    } else {
+                        number_word(word).map(str::to_string)
+                    }
+                };
+                let (Some(low), Some(high)) = (number(&caps["low"]), number(&caps["high"])) else {
+                    return caps[0].to_string();
+                };
+                let unit = caps.name("unit").map_or("", |m| m.as_str());
+                let exclusive = caps
+                    .name("ends")
+                    .is_some_and(|m| m.as_str().eq_ignore_ascii_case("exclusive"));
+
+                // A subject named by an article or a verb is what the range bounds
+                let subject = caps.name("subject").filter(|subject| {
+                    !connectives.contains(&subject.as_str().to_lowercase().as_str())
+                        && (caps.name("article").is_some() || caps.name("verb").is_some())
+                });
+                match subject {
+                    Some(subject) => {
+                        let op = if exclusive { "<" } else { "≤" };
+                        format!(
+                            "{}{}{}{}{}{}{}",
+                            low,
+                            unit,
+                            op,
+                            subject.as_str(),
+                            op,
+                            high,
+                            unit
+                        )
+                    }
+                    None => {
+                        let lead = &caps[0][..caps.name("between").unwrap().start()
+                            - caps.get(0).unwrap().start()];
+                        let (open, close) = if exclusive { ('(', ')') } else { ('[', ']') };
+                        format!("{}{}{}{},{}{}{}", lead, open, low, unit, high, unit, close)
+                    }
+                }
+            })
+            .to_string()
+    }
+
+    /// Rewrite enumerations into list, tuple, and set literals
+    /// "a list of 1, 2, and 3" → "[1,2,3]", "the tuple of a, b" → "⟨a,b⟩",
+    /// "the following: a, b, and c" → "{a,b,c}"
+    fn convert_list_literals(input: &str) -> String {
+        lazy_static! {
+            static ref ENUMERATION: Regex = Regex::new(&format!(
+                r"(?i)\b(?:(?:a|an|the)\s+)?(?:(list|array|sequence|tuple|record)\s+of|items|(following)\s*:)\s+({item}(?:\s*,\s*{item})*\s*,?\s+and\s+{item}|{item}(?:\s*,\s*{item})+)\b",
+                item = r#"(?:"[^"\n]*"|\d+(?:\.\d+)?|\w+)"#
+            ))
+            .unwrap();
+        }
+
+        ENUMERATION
+            .replace_all(input, |caps: &regex::Captures| {
+                let items: Vec<_> = LIST_SEPARATOR.split(&caps[3]).collect();
+                if caps.get(2).is_some() {
+                    return format!("{{{}}}", items.join(","));
+                }
+                match caps
+                    .get(1)
+                    .map(|kind| kind.as_str().to_lowercase())
+                    .as_deref()
+                {
+                    Some("tuple") | Some("record") => format!("⟨{}⟩", items.join(",")),
+                    _ => format!("[{}]", items.join(",")),
+                }
+            })
+            .to_string()
+    }
+
+    /// Rewrite quantifiers over several variables into one binding
+    /// "for all x and y in S" → "∀x,y∈S", "there exist a, b, and c in T" → "∃a,b,c∈T"
+    ///
+    /// Vocabulary words ("not", "true") are never variables, so "for all x and
+    /// not y in S" is left to the other passes.
+    fn convert_bound_variables(input: &str) -> String {
+        lazy_static! {
+            static ref BOUND: Regex = Regex::new(&format!(
+                r"(?i)\b(for\s+(?:all|every|each|any)|there\s+(?:exists?|is|are)|exists?)\s+((?:{var}\s*,\s*)*{var}\s*,?\s+and\s+{var}|{var}(?:\s*,\s*{var})+)\s+in\b",
+                var = r"[A-Za-z_]\w*"
+            ))
+            .unwrap();
+        }
+
+        BOUND
+            .replace_all(input, |caps: &regex::Captures| {
+                let vars: Vec<_> = LIST_SEPARATOR.split(&caps[2]).collect();
+                if vars
+                    .iter()
+                    .any(|v| PATTERN_TO_SYMBOL.contains_key(&v.to_lowercase()))
+                {
+                    return caps[0].to_string();
+                }
+                let quantifier = if caps[1].to_lowercase().starts_with("for") {
+                    "∀"
+                } else {
+                    "∃"
+                };
+                format!("{}{}∈", quantifier, vars.join(","))
+            })
+            .to_string()
+    }
+
+    /// Rewrite typed quantifiers, binding the variable to its type and constraint
+    /// "for every integer n greater than zero" → "∀n∈ℤ:n is greater than 0",
+    /// "for every integer n in S" → "∀n∈S:n∈ℤ"
+    ///
+    /// The variable is a letter with optional digits or underscores ("n", "x1"),
+    /// so "each string has a length" is left to the other passes.
+    fn convert_typed_quantifiers(input: &str) -> String {
+        TYPED_QUANTIFIER
+            .replace_all(input, |caps: &regex::Captures| {
+                let var = &caps["var"];
+                // "for all integers in S": the next word is vocabulary, not a variable
+                let lowercase = var.to_lowercase();
+                if PATTERN_TO_SYMBOL
+                    .keys()
+                    .any(|pattern| pattern.split_whitespace().next() == Some(lowercase.as_str()))
+                {
+                    return caps[0].to_string();
+                }
+                let symbol = TYPE_PATTERNS
+                    .iter()
+                    .find(|(p, _)| p.eq_ignore_ascii_case(&caps["type"]))
+                    .map_or("", |(_, symbol)| symbol);
+
+                let constraint =
+                    caps.name("comparator")
+                        .zip(caps.name("value"))
+                        .map(|(comparator, value)| {
+                            format!(
+                                "{var} is {} {}",
+                                comparator.as_str(),
+                                number_word(value.as_str()).unwrap_or(value.as_str())
+                            )
+                        });
+                match (caps.name("set"), constraint) {
+                    (Some(set), Some(constraint)) => {
+                        format!("∀{var}∈{}:{var}∈{symbol}∧{constraint}", set.as_str())
+                    }
+                    (Some(set), None) => format!("∀{var}∈{}:{var}∈{symbol}", set.as_str()),
+                    (None, Some(constraint)) => format!("∀{var}∈{symbol}:{constraint}"),
+                    (None, None) => format!("∀{var}∈{symbol}"),
+                }
+            })
+            .to_string()
+    }
+
+    /// Rewrite an action applied to every member of a collection
+    /// "for each item in the list, validate it" → "∀item∈list:validate(item)"
+    ///
+    /// The action is a single verb, optionally preceded by "do" and followed by
+    /// its object: "it", "them", or the bound variable. The clause must end there,
+    /// so "for each x in S, x is positive" is left to the other passes.
+    ///
+    /// The collection name is kept as an escaped span, so a name like "list"
+    /// isn't turned into its type symbol by the table.
+    fn convert_imperative_quantifiers(input: &str, spans: &mut Vec<String>) -> String {
+        lazy_static! {
+            static ref IMPERATIVE: Regex = Regex::new(
+                r"(?im)\bfor\s+(?:each|every)\s+(?P<var>[A-Za-z_]\w*)\s+in\s+(?:the\s+)?(?P<set>[A-Za-z_]\w*)\s*,\s*(?:do\s+)?(?P<action>[a-z]\w*)(?:\s+(?P<object>it|them|the\s+\w+|\w+))?(?P<end>[.;]|$)",
+            )
+            .unwrap();
+        }
+
+        IMPERATIVE
+            .replace_all(input, |caps: &regex::Captures| {
+                let var = &caps["var"];
+                let refers = caps.name("object").is_none_or(|object| {
+                    let object = object.as_str();
+                    let object = object.strip_prefix("the ").unwrap_or(object).trim();
+                    ["it", "them", var].contains(&object)
+                });
+                let vocabulary = |word: &str| PATTERN_TO_SYMBOL.contains_key(&word.to_lowercase());
+                let action = &caps["action"];
+                if !refers || action == var || vocabulary(var) || vocabulary(action) {
+                    return caps[0].to_string();
+                }
+                let set = escape_placeholder(spans.len());
+                spans.push(caps["set"].to_string());
+                format!("∀{var}∈{set}:{}({var}){}", action, &caps["end"])
+            })
+            .to_string()
+    }
+
+    /// Rewrite function signatures into arrow types, resolving type names
+    /// "a function from integers to booleans" → "ℤ→𝔹", "f maps ints and strings to bools" → "f:ℤ→𝕊→𝔹"
+    ///
+    /// "maps A to B" is a signature only when every part names a type; "maps x to y"
+    /// stays a value mapping.
+    fn convert_signatures(input: &str) -> String {
+        let type_symbol = |word: &str| -> Option<&'static str> {
+            let singular = word.strip_suffix(['s', 'S']).unwrap_or(word);
+            TYPE_ENTRIES
+                .iter()
+                .find(|e| {
+                    e.patterns
+                        .iter()
+                        .any(|p| p.eq_ignore_ascii_case(word) || p.eq_ignore_ascii_case(singular))
+                })
+                .map(|e| e.symbol)
+        };
+        lazy_static! {
+            static ref FUNCTION: Regex = Regex::new(&format!(
+                r"(?i)\b(?:(?P<subject>{name})\s+is\s+)?(?:(?:a|an|the)\s+)?(?:function|fn|mapping)\s+from\s+(?P<domain>{domain})\s+to\s+(?P<codomain>{name})\b",
+                name = SIGNATURE_NAME,
+                domain = SIGNATURE_DOMAIN
+            ))
+            .unwrap();
+            static ref MAPS: Regex = Regex::new(&format!(
+                r"(?i)\b(?P<subject>{name})\s+maps\s+(?P<domain>{domain})\s+to\s+(?P<codomain>{name})\b",
+                name = SIGNATURE_NAME,
+                domain = SIGNATURE_DOMAIN
+            ))
+            .unwrap();
+        }
+        const SIGNATURE_NAME: &str = r"[A-Za-z_]\w*";
+        const SIGNATURE_DOMAIN: &str =
+            r"[A-Za-z_]\w*(?:(?:\s*,\s*(?:and\s+)?|\s+and\s+)[A-Za-z_]\w*)*";
+
+        let arrow = |domain: &str, codomain: &str, strict: bool| -> Option<String> {
+            let types: Vec<_> = LIST_SEPARATOR
+                .split(domain)
+                .chain(std::iter::once(codomain))
+                .map(|word| {
+                    type_symbol(word)
+                        .map(str::to_string)
+                        .or((!strict).then(|| word.to_string()))
+                })
+                .collect::<Option<_>>()?;
+            Some(types.join("→"))
+        };
+
+        let result = FUNCTION.replace_all(input, |caps: &regex::Captures| {
+            let signature = arrow(&caps["domain"], &caps["codomain"], false).unwrap_or_default();
+            match caps.name("subject") {
+                Some(subject) => format!("{}:{}", subject.as_str(), signature),
+                None => signature,
+            }
+        });
+
+        MAPS.replace_all(&result, |caps: &regex::Captures| {
+            match arrow(&caps["domain"], &caps["codomain"], true) {
+                Some(signature) => format!("{}:{}", &caps["subject"], signature),
+                None => caps[0].to_string(),
+            }
+        })
+        .to_string()
+    }
+
+    /// Tighten converted set-builder braces
+    /// "{x ∈ S∣x > 0}" → "{x∈S∣x>0}"
+    fn tighten_set_builders(input: &str) -> String {
+        lazy_static! {
+            static ref BRACES: Regex = Regex::new(r"\{[^{}\n]*∣[^{}\n]*\}").unwrap();
+            static ref SPACED: Regex = Regex::new(r"\s*([<>≤≥≠≢≡∈∉⊆⊂∧∨∣])\s*").unwrap();
+        }
+        BRACES
+            .replace_all(input, |caps: &regex::Captures| {
+                SPACED.replace_all(&caps[0], "$1").to_string()
+            })
+            .to_string()
+    }
+
+    /// Convert conjoined comparisons that share a subject
+    /// "x≥5∧≤10" → "5≤x≤10", "x≤10∧≢3" → "x≤10∧x≢3"
+    fn convert_comparison_chains(input: &str) -> String {
+        lazy_static! {
+            static ref CHAIN: Regex =
+                Regex::new(r"(\w+)(?:\s+is)?\s*([≥>≤<≡≢])\s*(\w+)∧(?:is\s+)?([≥>≤<≡≢])\s*(\w+)")
+                    .unwrap();
+        }
+
+        CHAIN
+            .replace_all(input, |caps: &regex::Captures| {
+                let (subject, lower_op, lower) = (&caps[1], &caps[2], &caps[3]);
+                let (upper_op, upper) = (&caps[4], &caps[5]);
+
+                // A lower bound followed by an upper bound reads as a range
+                let flipped = match lower_op {
+                    "≥" => Some("≤"),
+                    ">" => Some("<"),
+                    _ => None,
+                };
+                match flipped {
+                    Some(op) if upper_op == "≤" || upper_op == "<" => {
+                        format!("{}{}{}{}{}", lower, op, subject, upper_op, upper)
+                    }
+                    _ => format!(
+                        "{}{}{}∧{}{}{}",
+                        subject, lower_op, lower, subject, upper_op, upper
+                    ),
+                }
+            })
+            .to_string()
+    }
+
+    /// Bind a clause's subject to a comparison with an optional unit
+    /// "response time must be <200 ms" → "response time<200ms" (or "response_time<200ms")
+    fn convert_bounds(input: &str, normalize_identifiers: bool) -> String {
+        lazy_static! {
+            static ref BOUND: Regex = Regex::new(
+                r"(?P<lead>^|[.;:,∧∨⇒∣(]\s*)(?:(?i:the|a|an)\s+)?(?P<subject>[A-Za-z_]\w*(?:\s+[A-Za-z_]\w*){0,3}?)\s+(?:(?i:must|should|shall|will)\s+)?(?i:be|is|are)\s*(?P<op>[<>≤≥≈])\s*(?P<value>\d+(?:\.\d+)?)(?:\s?(?P<unit>%|(?i:ms|s|sec|min|h|hr|kb|mb|gb|tb|px|m|km|kg|g)\b))?",
+            )
+            .unwrap();
+        }
+
+        BOUND
+            .replace_all(input, |caps: &regex::Captures| {
+                let subject = if normalize_identifiers {
+                    Self::to_identifier(&caps["subject"])
+                } else {
+                    caps["subject"].to_string()
+                };
+                format!(
+                    "{}{}{}{}{}",
+                    &caps["lead"],
+                    subject,
+                    &caps["op"],
+                    &caps["value"],
+                    caps.name("unit").map_or("", |m| m.as_str())
+                )
+            })
+            .to_string()
+    }
+
+    /// Join a multi-word phrase into a snake_case identifier
+    fn to_identifier(phrase: &str) -> String {
+        phrase
+            .split_whitespace()
+            .map(|word| word.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+
+    /// Convert common assignment patterns
     fn convert_assignments(input: &str) -> String {
+        lazy_static! {
+            static ref ASSIGNMENTS: [Regex; 3] = [
+                // "const x = 5"
+                Regex::new(r"(?i)const\s+(\w+)\s*=\s*(\S+)").unwrap(),
+                // "Define x as y"
+                Regex::new(r"(?i)Define\s+(\w+)\s+as\s+(\S+)").unwrap(),
+                // "let x = y"
+                Regex::new(r"(?i)let\s+(\w+)\s*=\s*(\S+)").unwrap(),
+            ];
+        }
+        let mut result = input.to_string();
+
+        // Each form becomes "x≜y"
+        for regex in ASSIGNMENTS.iter() {
+            result = regex.replace_all(&result, "$1≜$2").to_string();
+        }
+
+        result
+    }
+
+    /// Find words that weren't mapped to symbols
+    fn find_unmapped_words(result: &str, exclude_identifiers: bool) -> Vec<String> {
+        let ignore_words = [
+            "the", "with", "that", "this", "from", "into", "when", "where", "which", "what",
+        ];
+
+        let words: Vec<_> = WORD
+            .find_iter(result)
+            // Word-like symbols ("List", "Var") are conversions, not leftovers
+            .filter(|m| !SYMBOL_TO_PATTERN.contains_key(m.as_str()))
+            .filter(|m| {
+                !exclude_identifiers
+                    || !Self::is_identifier(m.as_str(), &result[..m.start()], &result[m.end()..])
+            })
+            .map(|m| m.as_str().to_lowercase())
+            .collect();
+
+        let mut unique: Vec<_> = words
+            .into_iter()
+            .filter(|w| !ignore_words.contains(&w.as_str()))
+            .collect();
+
+        unique.sort();
+        unique.dedup();
+        unique
+    }
+
+    /// Whether a word reads as an identifier: camelCase, or directly touching an operator
+    fn is_identifier(word: &str, before: &str, after: &str) -> bool {
+        let is_operator = Self::is_operator;
+        let camel_case = word
+            .chars()
+            .zip(word.chars().skip(1))
+            .any(|(a, b)| a.is_lowercase() && b.is_uppercase());
+
+        camel_case
+            || before.chars().next_back().is_some_and(is_operator)
+            || after.chars().next().is_some_and(is_operator)
+    }
+
+    /// Whether a char is, or is part of, a non-word table symbol
+    /// ASCII chars count only as whole symbols ("+"), not as the "!" of "∃!"
+    fn is_operator(c: char) -> bool {
+        OPERATOR_SYMBOLS.iter().any(|s| {
+            (s.len() == c.len_utf8() && s.starts_with(c)) || (!c.is_ascii() && s.contains(c))
+        })
+    }
+
+    /// Convert AISP symbols back to prose
+    /// Maintains spacing for readability while preserving semantic meaning
+    pub fn to_prose(input: &str) -> String {
+        Self::to_prose_with_style(input, ProseStyle::Terse)
+    }
+
+    /// Convert AISP symbols back to prose in the given style
+    /// `Natural` phrases quantifiers: "∃x:P" → "there exists an x such that P"
+    pub fn to_prose_with_style(input: &str, style: ProseStyle) -> String {
+        Self::to_prose_with(
+            input,
+            &ProseOptions {
+                style,
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Convert AISP symbols back to prose with style and per-symbol phrase overrides
+    ///
+    /// Text with no symbols, exponents, or hints is returned exactly as written,
+    /// spacing and all, before any pass runs ("3/4 done" and "10:30" included),
+    /// so running `to_prose` on its own output changes nothing.
+    pub fn to_prose_with(input: &str, options: &ProseOptions) -> String {
+        if !Self::has_notation(input) {
+            return input.to_string();
+        }
+        let expanded = Self::expand_symbols(input, options);
+
+        // Clean up multiple spaces and trim
+        let prose = Self::normalize_whitespace(&expanded);
+        if options.capitalize_sentences {
+            Self::capitalize_sentences(&prose)
+        } else {
+            prose
+        }
+    }
+
+    /// Capitalize pattern words that open a sentence
+    fn capitalize_sentences(input: &str) -> String {
+        lazy_static! {
+            static ref OPENING: Regex = Regex::new(r"(^|[.!?]\s+|\n\s*)([a-z]+)\b").unwrap();
+        }
+        OPENING
+            .replace_all(input, |caps: &regex::Captures| {
+                let word = &caps[2];
+                if word.len() < 2 || !PATTERN_WORDS.contains(word) {
+                    return caps[0].to_string();
+                }
+                let mut chars = word.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                format!("{}{}{}", &caps[1], first, chars.as_str())
+            })
+            .to_string()
+    }
+
+    /// Convert a multi-line AISP document back to prose
+    /// Keeps line and paragraph breaks so the block layout survives
+    pub fn document_to_prose(input: &str) -> String {
+        Self::normalize_whitespace_preserving_newlines(&Self::expand_symbols(
+            input,
+            &ProseOptions::default(),
+        ))
+    }
+
+    /// Replace AISP symbols with their primary prose patterns
+    fn expand_symbols(input: &str, options: &ProseOptions) -> String {
+        lazy_static! {
+            static ref EXPONENT: Regex = Regex::new(r"[⁰¹²³⁴⁵⁶⁷⁸⁹ⁿ]+").unwrap();
+            static ref CARET: Regex = Regex::new(r"\^([A-Za-z_]\w*)").unwrap();
+        }
+        let (protected, spans) = protect_escapes(input);
+        let stripped = Self::strip_source_annotations(&strip_hints(&protected));
+        let mut result = Self::phrase_counting_quantifiers(&stripped);
+        result = Self::phrase_imperative_quantifiers(&result);
+        result = Self::phrase_arithmetic(&result);
+        result = Self::phrase_comparatives(&result);
+        result = Self::phrase_statistics(&result);
+        result = Self::phrase_set_builders(&result);
+        result = Self::phrase_cardinality(&result);
+        result = Self::phrase_signatures(&result);
+        result = Self::phrase_ranges(&result);
+        result = Self::phrase_quantities(&result);
+        result = Self::phrase_list_literals(&result);
+        result = Self::phrase_conditionals(&result);
+        result = Self::phrase_conditions(&result);
+        if options.style == ProseStyle::Natural {
+            result = Self::phrase_quantifiers(&result);
+        }
+        result = Self::phrase_relational_operators(&result);
+
+        // Arbitrary exponents read as "to the power of n"; lone ² and ³ keep their entries
+        result = EXPONENT
+            .replace_all(&result, |caps: &regex::Captures| match &caps[0] {
+                "²" | "³" => caps[0].to_string(),
+                sup => format!(" to the power of {} ", from_superscript(sup)),
+            })
+            .to_string();
+
+        // A caret exponent without a superscript form: "x^k"
+        result = CARET
+            .replace_all(&result, " to the power of $1")
+            .to_string();
+
+        // One leftmost-longest pass over all symbols, so "∃!" wins over "∃"
+        let overridden: Vec<Option<&'static str>>;
+        let replacements: &[Option<&'static str>] = if options.overrides.phrases.is_empty() {
+            &DEFAULT_EXPANSIONS
+        } else {
+            overridden = SYMBOL_EXPANSIONS
+                .1
+                .iter()
+                .zip(DEFAULT_EXPANSIONS.iter())
+                .map(|(entry, default)| options.overrides.get(entry.symbol).or(*default))
+                .collect();
+            &overridden
+        };
+        let mut expanded = String::with_capacity(result.len());
+        SYMBOL_EXPANSIONS
+            .0
+            .replace_all_with(&result, &mut expanded, |m, symbol, dst| {
+                let before = result[..m.start()].chars().next_back();
+                let after = result[m.end()..].chars().next();
+                match replacements[m.pattern().as_usize()] {
+                    Some(phrase) if !Self::inside_word(symbol, before, after) => {
+                        dst.push_str(&Self::add_word_boundaries(phrase, before, after));
+                    }
+                    _ => dst.push_str(symbol),
+                }
+                true
+            });
+
+        restore_escapes(&expanded, &spans)
+    }
+
+    /// Whether a word symbol ("mod", "fix") sits inside a longer word, like "mod" in "model"
+    fn inside_word(symbol: &str, before: Option<char>, after: Option<char>) -> bool {
+        let word_char = |c: Option<char>| c.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_');
+        symbol.bytes().all(|b| b.is_ascii_alphabetic()) && (word_char(before) || word_char(after))
+    }
+
+    /// Whether `to_prose` has anything to expand: a table symbol, a conversion
+    /// hint, or notation only `convert` writes (exponents, bars, factorials,
+    /// statistics, and list or set literals)
+    ///
+    /// Fractions, percentages, and colons read the same in plain prose, so on
+    /// their own they are not notation.
+    fn has_notation(input: &str) -> bool {
+        lazy_static! {
+            static ref NOTATION: Regex = Regex::new(
+                r"[⁰¹²³⁴⁵⁶⁷⁸⁹ⁿ]|\^[A-Za-z_]|\|[^|\s]+\||\b(?:[A-Za-z]|\d+)!|\bE\[|\bVar\(|σ\(|\[[^\[\],\s]+(?:,[^\[\],\s]+)+\]|\{\w+(?:,\w+)+\}",
+            )
+            .unwrap();
+        }
+        NOTATION.is_match(input)
+            || HINT.is_match(input)
+            || SYMBOL_EXPANSIONS.0.find_iter(input).any(|m| {
+                let before = input[..m.start()].chars().next_back();
+                let after = input[m.end()..].chars().next();
+                !Self::inside_word(&input[m.range()], before, after)
+            })
+    }
+
+    /// Wrap text in an `\aisp{...}` span that `convert` and `to_prose` leave untouched
+    /// "∀x" → "\aisp{∀x}"; backslashes and closing braces are backslash-escaped
+    pub fn escape(text: &str) -> String {
+        let escaped = text.replace('\\', "\\\\").replace('}', "\\}");
+        format!("\\aisp{{{}}}", escaped)
+    }
+
+    /// Replace every `\aisp{...}` span with its literal contents
+    /// "the \aisp{∀} symbol" → "the ∀ symbol"
+    pub fn unescape(text: &str) -> String {
+        lazy_static! {
+            static ref ESCAPED_CHAR: Regex = Regex::new(r"\\(.)").unwrap();
+        }
+
+        ESCAPED_SPAN
+            .replace_all(text, |caps: &regex::Captures| {
+                ESCAPED_CHAR.replace_all(&caps[1], "$1").to_string()
+            })
+            .to_string()
+    }
+
+    /// Remove `⟨prose:"..."⟩` source annotations left by `annotate_source`
+    fn strip_source_annotations(input: &str) -> String {
+        lazy_static! {
+            static ref ANNOTATION: Regex =
+                Regex::new(r#"[ \t]*⟨prose:"(?:[^"\\]|\\.)*"⟩"#).unwrap();
+        }
+        ANNOTATION.replace_all(input, "").to_string()
+    }
+
+    /// Phrase set-builder braces
+    /// Handles cases like "{x∣x>0}" → "the set of all x such that x>0"
+    fn phrase_set_builders(input: &str) -> String {
+        lazy_static! {
+            static ref BUILDER: Regex =
+                Regex::new(r"\{([^{}∣\n]+?)[ \t]*∣[ \t]*([^{}\n]+)\}").unwrap();
+        }
+        BUILDER
+            .replace_all(input, "the set of all $1 such that $2")
+            .to_string()
+    }
+
+    /// Phrase attribute comparisons
+    /// Handles cases like "secure(A)>secure(B)" → "A is more secure than B"
+    fn phrase_comparatives(input: &str) -> String {
+        lazy_static! {
+            static ref COMPARISON: Regex = Regex::new(
+                r"\b([a-z]\w*)\(([A-Za-z_]\w*)\)\s*([<>])\s*([a-z]\w*)\(([A-Za-z_]\w*)\)"
+            )
+            .unwrap();
+        }
+        COMPARISON
+            .replace_all(input, |caps: &regex::Captures| {
+                if caps[1] != caps[4] {
+                    return caps[0].to_string();
+                }
+                let degree = if &caps[3] == ">" { "more" } else { "less" };
+                format!("{} is {} {} than {}", &caps[2], degree, &caps[1], &caps[5])
+            })
+            .to_string()
+    }
+
+    /// Phrase absolute values and factorials
+    /// Handles cases like "|x|" → "the absolute value of x", "n!" → "n factorial"
+    ///
+    /// Only a single lowercase letter or a number between bars is an absolute
+    /// value; named collections ("|S|", "|users|") are left for `phrase_cardinality`.
+    fn phrase_arithmetic(input: &str) -> String {
+        lazy_static! {
+            static ref ABSOLUTE: Regex = Regex::new(r"\|([a-z]|−?\d+(?:\.\d+)?)\|").unwrap();
+            static ref FACTORIAL: Regex = Regex::new(r"\b([A-Za-z_]\w*|\d+)!(=?)").unwrap();
+        }
+        let result = ABSOLUTE.replace_all(input, "the absolute value of $1");
+
+        FACTORIAL
+            .replace_all(&result, |caps: &regex::Captures| {
+                if caps[2].is_empty() {
+                    format!("{} factorial", &caps[1])
+                } else {
+                    caps[0].to_string()
+                }
+            })
+            .to_string()
+    }
+
+    /// Phrase cardinality bars
+    /// Handles cases like "|S|" → "the number of elements in S"
+    fn phrase_cardinality(input: &str) -> String {
+        lazy_static! {
+            static ref BARS: Regex = Regex::new(r"\|([A-Za-z_]\w*)\|").unwrap();
+        }
+        BARS.replace_all(input, "the number of elements in $1")
+            .to_string()
+    }
+
+    /// Phrase numeric percentages and fractions an operator applies to
+    /// Handles cases like "≥80%" → "≥80 percent", "x≤3/4" → "x≤3 out of 4"
+    ///
+    /// A quantity with no operator beside it reads the same in plain prose
+    /// ("50% done"), so it stays as written whatever else the text holds.
+    /// Colons are left alone ("10:30"), as are slashes in longer runs like "12/31/2026".
+    fn phrase_quantities(input: &str) -> String {
+        lazy_static! {
+            static ref PERCENT: Regex = Regex::new(r"(\d+(?:\.\d+)?)%").unwrap();
+            static ref FRACTION: Regex = Regex::new(r"\b(\d+)/(\d+)\b").unwrap();
+        }
+        let beside_operator = |text: &str, range: Range<usize>| {
+            text[..range.start].trim_end().chars().next_back().is_some_and(Self::is_operator)
+                || text[range.end..].trim_start().chars().next().is_some_and(Self::is_operator)
+        };
+        let result = PERCENT.replace_all(input, |caps: &regex::Captures| {
+            let whole = caps.get(0).unwrap();
+            if beside_operator(input, whole.range()) {
+                format!("{} percent ", &caps[1])
+            } else {
+                caps[0].to_string()
+            }
+        });
+        FRACTION
+            .replace_all(&result, |caps: &regex::Captures| {
+                let whole = caps.get(0).unwrap();
+                let in_run = result[..whole.start()].ends_with('/')
+                    || result[whole.end()..].starts_with('/');
+                if in_run || !beside_operator(&result, whole.range()) {
+                    caps[0].to_string()
+                } else {
+                    format!("{} out of {}", &caps[1], &caps[2])
+                }
+            })
+            .to_string()
+    }
+
+    /// Phrase counting quantifiers
+    /// Handles cases like "∃≥2 checks:pass" → "at least 2 of the checks pass"
+    fn phrase_counting_quantifiers(input: &str) -> String {
+        lazy_static! {
+            static ref COUNTING: Regex = Regex::new(r"∃([≥≤=])(\d+) ([A-Za-z_]\w*):").unwrap();
+        }
+        COUNTING
+            .replace_all(input, |caps: &regex::Captures| {
+                let bound = match &caps[1] {
+                    "≥" => "at least",
+                    "≤" => "at most",
+                    _ => "exactly",
+                };
+                format!("{} {} of the {} ", bound, &caps[2], &caps[3])
+            })
+            .to_string()
+    }
+
+    /// Phrase an action applied to every member of a collection
+    /// Handles cases like "∀item∈S:validate(item)" → "for each item in S, validate it"
+    fn phrase_imperative_quantifiers(input: &str) -> String {
+        lazy_static! {
+            static ref IMPERATIVE: Regex =
+                Regex::new(r"∀([A-Za-z_]\w*)∈([A-Za-z_]\w*):([a-z]\w*)\(([A-Za-z_]\w*)\)").unwrap();
+        }
+        IMPERATIVE
+            .replace_all(input, |caps: &regex::Captures| {
+                if caps[1] != caps[4] {
+                    return caps[0].to_string();
+                }
+                format!("for each {} in {}, {} it", &caps[1], &caps[2], &caps[3])
+            })
+            .to_string()
+    }
+
+    /// Phrase expectation and spread notation
+    /// Handles cases like "E[X]" → "the expected value of X", "σ(X)" → "the standard deviation of X"
+    ///
+    /// `P(X)` is left as written: it reads equally as a predicate applied to X,
+    /// and `P(A∣B)` already phrases as "P(A given B)", which converts back.
+    fn phrase_statistics(input: &str) -> String {
+        lazy_static! {
+            static ref EXPECTATION: Regex = Regex::new(r"\bE\[([^\[\]]+)\]").unwrap();
+            static ref VARIANCE: Regex = Regex::new(r"\bVar\(([^()]+)\)").unwrap();
+            static ref DEVIATION: Regex = Regex::new(r"σ\(([^()]+)\)").unwrap();
+        }
+        let result = EXPECTATION.replace_all(input, "the expected value of $1");
+        let result = VARIANCE.replace_all(&result, "the variance of $1");
+        DEVIATION
+            .replace_all(&result, "the standard deviation of $1")
+            .to_string()
+    }
+
+    /// Phrase arrow types between type symbols
+    /// Handles cases like "f:ℤ→𝔹" → "f is a function from integer to boolean"
+    fn phrase_signatures(input: &str) -> String {
+        lazy_static! {
+            static ref SIGNATURE: Regex = {
+                let alternation: Vec<_> = TYPE_ENTRIES
+                    .iter()
+                    .map(|e| escape_regex(e.symbol))
+                    .collect();
+                Regex::new(&format!(
+                    r"(?:\b([A-Za-z_]\w*):)?((?:{types})(?:→(?:{types}))+)",
+                    types = alternation.join("|")
+                ))
+                .unwrap()
+            };
+        }
+
+        SIGNATURE
+            .replace_all(input, |caps: &regex::Captures| {
+                let names: Vec<_> = caps[2]
+                    .split('→')
+                    .map(|symbol| {
+                        TYPE_ENTRIES
+                            .iter()
+                            .find(|e| e.symbol == symbol)
+                            .map_or(symbol, |e| e.patterns[0])
+                    })
+                    .collect();
+                let (codomain, domain) = names.split_last().unwrap();
+                let function = format!("a function from {} to {}", domain.join(" and "), codomain);
+                match caps.get(1) {
+                    Some(subject) => format!("{} is {}", subject.as_str(), function),
+                    None => function,
+                }
+            })
+            .to_string()
+    }
+
+    /// Phrase numeric ranges
+    /// Handles cases like "1≤x≤100" → "x is between 1 and 100"
+    fn phrase_ranges(input: &str) -> String {
+        lazy_static! {
+            static ref RANGE: Regex = Regex::new(
+                r"(-?\d+(?:\.\d+)?)(%|ms|s|sec|min|h|hr|kb|mb|gb|tb|px|m|km|kg|g)?([≤<])([A-Za-z_]\w*)([≤<])(-?\d+(?:\.\d+)?)(%|ms|s|sec|min|h|hr|kb|mb|gb|tb|px|m|km|kg|g)?\b",
+            )
+            .unwrap();
+        }
+        RANGE
+            .replace_all(input, |caps: &regex::Captures| {
+                let unit = caps.get(7).map_or("", |m| m.as_str());
+                match (&caps[3], &caps[5]) {
+                    ("≤", "≤") => format!(
+                        "{} is between {} and {}{}",
+                        &caps[4], &caps[1], &caps[6], unit
+                    ),
+                    ("<", "<") => format!(
+                        "{} is between {} and {}{}, exclusive",
+                        &caps[4], &caps[1], &caps[6], unit
+                    ),
+                    _ => caps[0].to_string(),
+                }
+            })
+            .to_string()
+    }
+
+    /// Phrase list, tuple, and set literals
+    /// Handles cases like "[1,2,3]" → "a list of 1, 2, and 3" and
+    /// "{a,b,c}" → "the following: a, b, and c"
+    fn phrase_list_literals(input: &str) -> String {
+        let enumerate = |items: &str| {
+            let items: Vec<_> = items.split(',').collect();
+            match items.split_last() {
+                Some((last, [first])) => format!("{} and {}", first, last),
+                Some((last, rest)) => format!("{}, and {}", rest.join(", "), last),
+                None => String::new(),
+            }
+        };
+        lazy_static! {
+            static ref LIST: Regex = Regex::new(r"\[([^\[\],\s]+(?:,[^\[\],\s]+)+)\]").unwrap();
+            static ref TUPLE: Regex = Regex::new(r"⟨(\w+(?:,\w+)+)⟩").unwrap();
+            static ref SET: Regex = Regex::new(r"\{(\w+(?:,\w+)+)\}").unwrap();
+        }
+        let result = LIST.replace_all(input, |caps: &regex::Captures| {
+            format!("a list of {}", enumerate(&caps[1]))
+        });
+        let result = TUPLE.replace_all(&result, |caps: &regex::Captures| {
+            format!("a tuple of {}", enumerate(&caps[1]))
+        });
+        SET.replace_all(&result, |caps: &regex::Captures| {
+            format!("the following: {}", enumerate(&caps[1]))
+        })
+        .to_string()
+    }
+
+    /// Phrase `∣` separators outside set builders and conditionals
+    /// Handles cases like "∀x∈S∣P" → "∀x∈S such that P" and "P(A∣B)" → "P(A given B)"
+    fn phrase_conditions(input: &str) -> String {
+        lazy_static! {
+            static ref GUARDED: Regex =
+                Regex::new(r"([∀∃∄]!?[ \t]*[^\s∣:;,]+)[ \t]*∣[ \t]*").unwrap();
+            static ref GIVEN: Regex =
+                Regex::new(r"\(([^()∣\n]+?)[ \t]*∣[ \t]*([^()∣\n]+)\)").unwrap();
+        }
+        let result = GUARDED.replace_all(input, "$1 such that ");
+        GIVEN.replace_all(&result, "($1 given $2)").to_string()
+    }
+
+    /// Phrase quantified statements grammatically
+    /// Handles cases like "∀x∈S:P" → "∀x∈S, P holds" and "∃x:P" → "∃ an x such that P"
+    fn phrase_quantifiers(input: &str) -> String {
+        lazy_static! {
+            static ref UNIVERSAL: Regex =
+                Regex::new(r"∀[ \t]*([^:;\n]+?)[ \t]*:[ \t]*([^;\n}⟩]+)").unwrap();
+            static ref EXISTENTIAL: Regex =
+                Regex::new(r"∃[ \t]*([^!:;\n][^:;\n]*?)[ \t]*:[ \t]*([^;\n}⟩]+)").unwrap();
+        }
+        let result = UNIVERSAL.replace_all(input, "∀ $1, $2 holds");
+
+        EXISTENTIAL
+            .replace_all(&result, "∃ an $1 such that $2")
+            .to_string()
+    }
+
+    /// Phrase guarded branches as if/then/else
+    /// Handles cases like "x⇒y∣¬x⇒z" → "if x then y else z" and "if x then y∣z" → "if x then y else z"
+    fn phrase_conditionals(input: &str) -> String {
+        lazy_static! {
+            static ref ELSE: Regex =
+                Regex::new(r"(?i)(\bif\s+[^⇒∣\n]+?(?:⇒|\s+then\s+)[^⇒∣\n]+?)[ \t]*∣[ \t]*").unwrap();
+            static ref BRANCHES: Regex = Regex::new(
+                r"([^⇒∣\n]+?)[ \t]*⇒[ \t]*([^⇒∣\n]+?)[ \t]*∣[ \t]*¬([^⇒∣\n]+?)[ \t]*⇒[ \t]*([^⇒∣\n]+)",
+            )
+            .unwrap();
+        }
+
+        let result = BRANCHES.replace_all(input, |caps: &regex::Captures| {
+            let guarded = &caps[1];
+            let negated = caps[3].trim();
+            let condition = negated
+                .strip_prefix('(')
+                .and_then(|c| c.strip_suffix(')'))
+                .unwrap_or(negated);
+
+            // The else branch must negate the same condition
+            match guarded.strip_suffix(condition) {
+                Some(prefix) => format!(
+                    "{}if {} then {} else {}",
+                    prefix, condition, &caps[2], &caps[4]
+                ),
+                None => caps[0].to_string(),
+            }
+        });
+
+        // A lone bar after "if ... then" is the else branch
+        ELSE.replace_all(&result, "$1 else ").to_string()
+    }
+
+    /// Phrase relational operators that sit between two operands
+    /// Handles cases like "A↔B" → "A is bidirectionally related to B"
+    fn phrase_relational_operators(input: &str) -> String {
+        lazy_static! {
+            /// Each operator between two operands, with its replacement
+            static ref RELATIONS: Vec<(Regex, String)> = [
+                ("↔", "is bidirectionally related to"),
+                ("⇔", "is equivalent to"),
+                ("≡", "is identical to"),
+            ]
+            .into_iter()
+            .map(|(op, phrase)| {
+                let regex =
+                    Regex::new(&format!(r"(\w+)[ \t]*{}[ \t]*(\w+)", escape_regex(op))).unwrap();
+                (regex, format!("$1 {} $2", phrase))
+            })
+            .collect();
+        }
         let mut result = input.to_string();
 
-        // Convert "const x = 5" to "x≜5"
-        if let Ok(regex) = Regex::new(r"(?i)const\s+(\w+)\s*=\s*(\S+)") {
-            result = regex.replace_all(&result, "$1≜$2").to_string();
+        for (regex, replacement) in RELATIONS.iter() {
+            result = regex.replace_all(&result, replacement.as_str()).to_string();
+        }
+
+        result
+    }
+
+    /// Pad a phrase expanded from a symbol with a space on each side where it
+    /// meets other text: "x∈S" → "x in S"
+    ///
+    /// Only the expanded phrase gets boundaries, so camelCase identifiers
+    /// ("userId", "aB" in "aB−cD") are left whole rather than split. This
+    /// applies to every input, not just arithmetic: `to_prose` no longer
+    /// splits "userId" into "user Id".
+    fn add_word_boundaries(phrase: &str, before: Option<char>, after: Option<char>) -> String {
+        let touches = |c: Option<char>| c.is_some_and(|c| !c.is_whitespace());
+        let pad = |c: Option<char>| if touches(c) { " " } else { "" };
+        format!("{}{}{}", pad(before), phrase, pad(after))
+    }
+
+    /// Normalize whitespace in text
+    fn normalize_whitespace(input: &str) -> String {
+        lazy_static! {
+            static ref MULTIPLE_SPACES: Regex = Regex::new(r"\s+").unwrap();
+            static ref SPACE_BEFORE_PUNCT: Regex = Regex::new(r"\s+([.,;:!?])").unwrap();
+        }
+        let result = MULTIPLE_SPACES.replace_all(input, " ");
+
+        // Clean up spaces around punctuation
+        let result = SPACE_BEFORE_PUNCT.replace_all(&result, "$1");
+
+        // Clean up spaces just inside matched brackets
+        let result = Self::tighten_brackets(&result, &['(', '[', '{']);
+
+        result.trim().to_string()
+    }
+
+    /// Normalize whitespace in text, keeping line and paragraph breaks
+    fn normalize_whitespace_preserving_newlines(input: &str) -> String {
+        lazy_static! {
+            static ref HORIZONTAL_SPACES: Regex = Regex::new(r"[^\S\n]+").unwrap();
+            static ref AROUND_NEWLINE: Regex = Regex::new(r" ?\n ?").unwrap();
+            static ref BLANK_LINES: Regex = Regex::new(r"\n{3,}").unwrap();
+            static ref SPACE_BEFORE_PUNCT: Regex = Regex::new(r" ([.,;:!?])").unwrap();
+        }
+        let result = HORIZONTAL_SPACES.replace_all(input, " ");
+
+        // Strip spaces around line breaks and cap blank lines at one paragraph break
+        let result = AROUND_NEWLINE.replace_all(&result, "\n");
+        let result = BLANK_LINES.replace_all(&result, "\n\n");
+
+        // Clean up spaces around punctuation and brackets without crossing lines
+        let result = SPACE_BEFORE_PUNCT.replace_all(&result, "$1");
+        let result = Self::tighten_brackets(&result, &['(', '[', '{']);
+
+        result.trim().to_string()
+    }
+
+    /// Byte offsets of parentheses without a partner, in input order
+    ///
+    /// # Example
+    /// ```
+    /// use rosetta_aisp::RosettaStone;
+    ///
+    /// assert!(RosettaStone::unbalanced_parentheses("(a or b) and c").is_empty());
+    /// assert_eq!(RosettaStone::unbalanced_parentheses("a or b) and (c"), vec![6, 12]);
+    /// ```
+    pub fn unbalanced_parentheses(input: &str) -> Vec<usize> {
+        let (_, unmatched) = Self::match_brackets(input);
+        unmatched
+            .into_iter()
+            .filter(|&i| matches!(input.as_bytes()[i], b'(' | b')'))
+            .collect()
+    }
+
+    /// Pair up (), [], and {} brackets
+    /// Returns (open and close byte offsets of each matched pair, offsets of unmatched brackets)
+    fn match_brackets(input: &str) -> (Vec<(usize, usize)>, Vec<usize>) {
+        let mut stack: Vec<(usize, char)> = Vec::new();
+        let mut pairs = Vec::new();
+        let mut unmatched = Vec::new();
+
+        for (i, c) in input.char_indices() {
+            let open = match c {
+                '(' | '[' | '{' => {
+                    stack.push((i, c));
+                    continue;
+                }
+                ')' => '(',
+                ']' => '[',
+                '}' => '{',
+                _ => continue,
+            };
+            match stack.last() {
+                Some(&(start, top)) if top == open => {
+                    stack.pop();
+                    pairs.push((start, i));
+                }
+                _ => unmatched.push(i),
+            }
+        }
+
+        unmatched.extend(stack.into_iter().map(|(i, _)| i));
+        unmatched.sort_unstable();
+        (pairs, unmatched)
+    }
+
+    /// Drop horizontal space just inside matched brackets opened by one of `openers`
+    /// "( a∧b )" → "(a∧b)"; an unmatched bracket keeps its spacing
+    fn tighten_brackets(input: &str, openers: &[char]) -> String {
+        let (pairs, _) = Self::match_brackets(input);
+        let mut dropped = vec![false; input.len()];
+        let is_space = |c: char| c == ' ' || c == '\t';
+
+        for (open, close) in pairs {
+            if !input[open..].starts_with(openers) {
+                continue;
+            }
+            let inner = &input[open + 1..close];
+            let leading = inner.len() - inner.trim_start_matches(is_space).len();
+            let trailing = inner.len() - inner.trim_end_matches(is_space).len();
+            dropped[open + 1..open + 1 + leading].fill(true);
+            dropped[close - trailing..close].fill(true);
+        }
+
+        input
+            .char_indices()
+            .filter(|&(i, _)| !dropped[i])
+            .map(|(_, c)| c)
+            .collect()
+    }
+
+    /// Whether the whole text is one parenthesized group: "(a∧b)" but not "(a)∧(b)"
+    fn is_grouped(text: &str) -> bool {
+        text.starts_with('(')
+            && Self::match_brackets(text)
+                .0
+                .contains(&(0, text.len().saturating_sub(1)))
+    }
+
+    /// Round-trip every document (prose → AISP → prose) and score semantic preservation
+    /// `worst` lists up to five of the lowest-scoring documents for triage
+    pub fn verify_corpus(docs: &[&str], min_similarity: f64) -> CorpusReport {
+        let scores: Vec<f64> = docs
+            .iter()
+            .map(|doc| {
+                let (aisp, _, _) = Self::convert(doc);
+                Self::semantic_similarity(doc, &Self::to_prose(&aisp))
+            })
+            .collect();
+
+        let mean_similarity = if scores.is_empty() {
+            1.0
+        } else {
+            scores.iter().sum::<f64>() / scores.len() as f64
+        };
+
+        let failures: Vec<usize> = scores
+            .iter()
+            .enumerate()
+            .filter(|(_, score)| **score < min_similarity)
+            .map(|(i, _)| i)
+            .collect();
+
+        let mut worst: Vec<(usize, f64)> = scores.iter().copied().enumerate().collect();
+        worst.sort_by(|a, b| a.1.total_cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        worst.truncate(5);
+
+        CorpusReport {
+            passed: failures.is_empty(),
+            scores,
+            mean_similarity,
+            failures,
+            worst,
+        }
+    }
+
+    /// Normalize text for semantic comparison (removes formatting differences)
+    pub fn normalize_for_comparison(input: &str) -> String {
+        let lowercase = input.to_lowercase();
+        let normalized = Self::normalize_whitespace(&lowercase);
+
+        // Remove punctuation for semantic comparison
+        lazy_static! {
+            static ref PUNCTUATION: Regex = Regex::new(r#"[.,;:!?"']"#).unwrap();
+        }
+        PUNCTUATION.replace_all(&normalized, "").trim().to_string()
+    }
+
+    /// Check semantic equivalence between two texts
+    /// Returns similarity score from 0.0 to 1.0
+    pub fn semantic_similarity(text1: &str, text2: &str) -> f64 {
+        let norm1 = Self::normalize_for_comparison(text1);
+        let norm2 = Self::normalize_for_comparison(text2);
+
+        // Extract words
+        let words1: HashSet<_> = norm1.split_whitespace().collect();
+        let words2: HashSet<_> = norm2.split_whitespace().collect();
+
+        if words1.is_empty() && words2.is_empty() {
+            return 1.0;
+        }
+
+        // Jaccard similarity
+        let intersection = words1.intersection(&words2).count();
+        let union = words1.union(&words2).count();
+
+        if union == 0 {
+            1.0
+        } else {
+            intersection as f64 / union as f64
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prose_to_symbol() {
+        assert_eq!(prose_to_symbol("for all"), Some("∀"));
+        assert_eq!(prose_to_symbol("exists"), Some("∃"));
+        assert_eq!(prose_to_symbol("unknown"), None);
+    }
+
+    #[test]
+    fn test_convert_bounds_with_units() {
+        let (result, _, _) = RosettaStone::convert("response time must be under 200ms");
+        assert_eq!(result, "response time<200ms");
+
+        let options = RosettaOptions {
+            normalize_identifiers: true,
+            ..Default::default()
+        };
+        let (result, _, _) =
+            RosettaStone::convert_with("response time must be under 200ms", &options);
+        assert_eq!(result, "response_time<200ms");
+        let (result, _, _) =
+            RosettaStone::convert_with("The Upload Size should be at most 5 MB", &options);
+        assert_eq!(result, "upload_size≤5MB");
+        let (result, _, _) = RosettaStone::convert_with("disk usage is below 2 %", &options);
+        assert_eq!(result, "disk_usage<2%");
+    }
+
+    #[test]
+    fn test_convert_exponents() {
+        let (result, _, _) = RosettaStone::convert("vector in R^3");
+        assert!(result.ends_with("∈ℝ³"), "{}", result);
+        let (result, _, _) = RosettaStone::convert("x^2 plus y^10 plus Z^n");
+        assert_eq!(result, "x² + y¹⁰ + ℤⁿ");
+        let (result, _, _) = RosettaStone::convert("x to the power of 4");
+        assert_eq!(result, "x⁴");
+
+        assert_eq!(RosettaStone::to_prose("x⁴"), "x to the power of 4");
+        assert_eq!(RosettaStone::to_prose("ℝⁿ"), "real to the power of n");
+        assert_eq!(RosettaStone::to_prose("y¹⁰"), "y to the power of 10");
+        assert_eq!(RosettaStone::to_prose("x²"), "x squared");
+        assert_eq!(RosettaStone::to_prose("◊⁺⁺"), "platinum");
+
+        let (result, _, _) = RosettaStone::convert("x raised to the 3rd power");
+        assert_eq!(result, "x³");
+        let (result, _, _) = RosettaStone::convert("x to the power of k");
+        assert_eq!(result, "x^k");
+        assert_eq!(RosettaStone::to_prose("x^k"), "x to the power of k");
+    }
+
+    #[test]
+    fn test_convert_arithmetic() {
+        let cases = [
+            ("x modulo 7", "x mod 7"),
+            ("the absolute value of x", "|x|"),
+            ("n factorial", "n!"),
+            ("the factorial of 5 is 120", "5! is 120"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+            let prose = RosettaStone::to_prose(expected);
+            assert_eq!(RosettaStone::convert(&prose).0, expected, "{:?}", prose);
+        }
+
+        assert_eq!(RosettaStone::to_prose("n!"), "n factorial");
+        assert_eq!(RosettaStone::to_prose("∃!x"), "exists unique x");
+        // Bars around a named collection are still a count
+        assert_eq!(
+            RosettaStone::to_prose("|users|"),
+            "the number of elements in users"
+        );
+        // Word symbols expand only as whole words
+        assert_eq!(RosettaStone::to_prose("the model"), "the model");
+    }
+
+    #[test]
+    fn test_convert_comparison_chains() {
+        let (result, _, _) = RosettaStone::convert("x is at least 5 and at most 10");
+        assert_eq!(result, "5≤x≤10");
+        let (result, _, _) = RosettaStone::convert("x is greater than 0 and less than n");
+        assert_eq!(result, "0<x<n");
+        let (result, _, _) = RosettaStone::convert("x is at most 10 and not equal 3");
+        assert_eq!(result, "x≤10∧x≢3");
+    }
+
+    #[test]
+    fn test_convert_set_builders() {
+        let (result, _, _) = RosettaStone::convert("the set of all x such that x > 0");
+        assert_eq!(result, "{x∣x>0}");
+        let (result, _, _) =
+            RosettaStone::convert("Let P be the set of all n in N where n is greater than 2.");
+        assert!(result.contains("{n∈N∣n>2}."), "{}", result);
+        let (result, _, _) = RosettaStone::convert("set of all y | y is at least 1");
+        assert_eq!(result, "{y∣y≥1}");
+
+        let prose = RosettaStone::to_prose("{x∣x>0}");
+        assert_eq!(prose, "the set of all x such that x greater than 0");
+        let (back, _, _) = RosettaStone::convert(&RosettaStone::to_prose("{n∈N∣n≥2}"));
+        assert_eq!(back, "{n∈N∣n≥2}");
+    }
+
+    #[test]
+    fn test_convert_cardinality() {
+        let cases = [
+            ("the number of elements in S", "|S|"),
+            ("count of users", "|users|"),
+            ("the size of Q equals the cardinality of R", "|Q|≡|R|"),
+            ("the size of the file is large", "|file| is large"),
+            ("the number of the beast", "|beast|"),
+            ("the number of elements in the queue", "|queue|"),
+            ("count of 5", "count of 5"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        let prose = RosettaStone::to_prose("|S|≤10");
+        assert!(
+            prose.starts_with("the number of elements in S"),
+            "{}",
+            prose
+        );
+        let (back, _, _) = RosettaStone::convert(&prose);
+        assert_eq!(back.replace(' ', ""), "|S|≤10");
+    }
+
+    #[test]
+    fn test_convert_quantities() {
+        let cases = [
+            ("at least 80 percent", "≥80%"),
+            (
+                "availability must be at least 99.9 percent",
+                "availability≥99.9%",
+            ),
+            ("three out of four", "3/4"),
+            ("the ratio of 3 to 4", "3/4"),
+            ("x out of range", "x out of range"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        for aisp in ["≥80%", "3/4", "x≤3/4", "3/4 checks pass"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+        let (aisp, _, _) = RosettaStone::convert("3 out of 4 checks pass");
+        assert_eq!(aisp, "3/4 checks pass");
+        assert_eq!(RosettaStone::convert(&RosettaStone::to_prose(&aisp)).0, aisp);
+
+        // Times and dates are not ratios or fractions
+        assert_eq!(
+            RosettaStone::to_prose("Deploy at 10:30 daily"),
+            "Deploy at 10:30 daily"
+        );
+        assert_eq!(
+            RosettaStone::to_prose("deploy at 10:30 daily∧x≥5"),
+            "deploy at 10:30 daily and x greater than or equal 5"
+        );
+        assert_eq!(
+            RosettaStone::to_prose("due 12/31/2026∧x≥5"),
+            "due 12/31/2026 and x greater than or equal 5"
+        );
+    }
+
+    #[test]
+    fn test_convert_statistics() {
+        let cases = [
+            ("the probability of X", "P(X)"),
+            ("the probability that X given Y", "P(X∣Y)"),
+            ("the probability of A conditioned on B", "P(A∣B)"),
+            ("P of A conditioned on B", "P(A∣B)"),
+            ("P of A given B", "P(A∣B)"),
+            ("the probability of X = 1", "P(X=1)"),
+            ("the expected value of X", "E[X]"),
+            (
+                "the variance of X and the standard deviation of Y",
+                "Var(X)∧σ(Y)",
+            ),
+        ];
+        for (input, expected) in cases {
+            let (output, _, unmapped) = RosettaStone::convert(input);
+            assert_eq!(output, expected, "{:?}", input);
+            assert!(unmapped.is_empty(), "{:?}", unmapped);
+        }
+
+        for aisp in ["E[X]", "Var(X)", "σ(X)"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert!(prose.starts_with("the "), "{:?}", prose);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+        // Probability stays in function notation, which also reads as a predicate
+        for aisp in ["P(X∣Y)", "P(X)"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+        assert_eq!(category_of("Var"), Some("statistics"));
+        assert_eq!(band_of("σ"), None);
+    }
+
+    #[test]
+    fn test_convert_signatures() {
+        let cases = [
+            ("a function from integers to booleans", "ℤ→𝔹"),
+            ("f is a function from integers to booleans", "f:ℤ→𝔹"),
+            ("f maps integers to booleans", "f:ℤ→𝔹"),
+            ("a function from integers and strings to booleans", "ℤ→𝕊→𝔹"),
+            ("a function from A, B, and C to D", "A→B→C→D"),
+            ("define f as a function from integer to string", "f≜ℤ→𝕊"),
+            // Value mappings are not signatures
+            ("f maps x to y", "f maps x→y"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        for aisp in ["f:ℤ→𝔹", "ℤ→𝕊→𝔹"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert!(prose.contains("a function from"), "{:?}", prose);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+    }
+
+    #[test]
+    fn test_convert_subtyping() {
+        let cases = [
+            ("a Dog is a kind of Animal", "a Dog⊑Animal"),
+            ("Admin inherits from User", "Admin⊑User"),
+            ("class B extends A", "class B⊑A"),
+            // A plain "is a" is still a definition
+            ("x is a number", "x≜ℝ"),
+            ("User is a struct", "User≜struct"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        let prose = RosettaStone::to_prose("Dog⊑Animal");
+        assert_eq!(prose, "Dog is a subtype of Animal");
+        assert_eq!(RosettaStone::convert(&prose).0, "Dog⊑Animal");
+    }
+
+    #[test]
+    fn test_convert_counting_quantifiers() {
+        let cases = [
+            ("at least two of the checks pass", "∃≥2 checks:pass"),
+            ("exactly three users are admins", "∃=3 users:are admins"),
+            ("at most one of the jobs fails.", "∃≤1 jobs:fails."),
+            (
+                "If at least 3 of the replicas are healthy then deploy",
+                "If ∃≥3 replicas:are healthy⇒deploy",
+            ),
+            // Counts of one keep the table's quantifiers
+            ("at least one of the checks passes", "∃ checks:passes"),
+            ("exactly one of the nodes is leader", "∃! nodes:is leader"),
+            // Without "of" a count must open the clause; otherwise it is a bound
+            ("x is at least 5 ms", "x≥5ms"),
+            ("there is at least one x in S", "∃ x∈S"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        for aisp in ["∃≥2 checks:pass", "∃≤1 jobs:fails", "∃=3 users:are admins"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert!(prose.contains(" of the "), "{:?}", prose);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+    }
+
+    #[test]
+    fn test_convert_ranges() {
+        let cases = [
+            ("a value between 1 and 100", "1≤value≤100"),
+            ("x must be between 1 and 100", "1≤x≤100"),
+            (
+                "latency must be between 100 and 200 ms",
+                "100ms≤latency≤200ms",
+            ),
+            ("x lies between -1.5 and 2.5", "-1.5≤x≤2.5"),
+            ("x is between 0 and 1, exclusive", "0<x<1"),
+            ("n is between one and ten and y is true", "1≤n≤10∧y is ⊤"),
+            // Without a subject the range is an interval
+            ("pick between 1 and 10", "pick [1,10]"),
+            ("between 1 and 10 exclusive", "(1,10)"),
+            // Only numeric bounds form a range
+            (
+                "the link between users and admins",
+                "the link between users∧admins",
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        for aisp in ["1≤value≤100", "0<x<1", "100ms≤latency≤200ms"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert!(prose.contains("between"), "{:?}", prose);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+    }
+
+    #[test]
+    fn test_convert_list_literals() {
+        let cases = [
+            ("a list of 1, 2, and 3", "[1,2,3]"),
+            ("the items a, b, c", "[a,b,c]"),
+            ("an array of 1.5 and 2", "[1.5,2]"),
+            ("the tuple of x and y", "⟨x,y⟩"),
+            ("x in a list of true and false", "x∈[⊤,⊥]"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        // "the following:" introduces a set, without a conjunction before the last item
+        let (result, _, _) = RosettaStone::convert(
+            "the system must support the following: authentication, logging, and caching.",
+        );
+        assert_eq!(
+            result,
+            "the system must support {authentication,logging,caching}."
+        );
+        assert!(!result.contains('∧'));
+
+        // A lone item is not an enumeration
+        assert!(RosettaStone::convert("a list of users").0.contains("List"));
+
+        assert_eq!(RosettaStone::to_prose("[1,2,3]"), "a list of 1, 2, and 3");
+        assert_eq!(
+            RosettaStone::to_prose("{read,write}"),
+            "the following: read and write"
+        );
+        for aisp in ["[1,2,3]", "⟨a,b⟩", "ρ≔⟨domain,types,rules⟩", "{a,b,c}"] {
+            let prose = RosettaStone::to_prose(aisp);
+            assert_eq!(RosettaStone::convert(&prose).0, aisp, "{:?}", prose);
+        }
+    }
+
+    #[test]
+    fn test_convert_typed_quantifiers() {
+        let cases = [
+            ("for every integer n greater than zero", "∀n∈ℤ:n>0"),
+            ("for all natural numbers k less than 10", "∀k∈ℕ:k<10"),
+            ("for each real x at least 0", "∀x∈ℝ:x≥0"),
+            ("for every string s", "∀s∈𝕊"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        // Without a variable the type stays a plain substitution
+        assert_eq!(RosettaStone::convert("for all integers in S").0, "∀ ℤ∈S");
+
+        // A membership binds the collection, keeping the type as a constraint
+        assert_eq!(
+            RosettaStone::convert("for every integer n in S").0,
+            "∀n∈S:n∈ℤ"
+        );
+        assert_eq!(
+            RosettaStone::convert("for each integer i in the items at least 1").0,
+            "∀i∈items:i∈ℤ∧i≥1"
+        );
+
+        // Words after the type are not variables
+        for input in [
+            "each string has a length",
+            "every real number is positive",
+            "each integer greater than zero",
+        ] {
+            assert!(!RosettaStone::convert(input).0.contains('∈'), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_arrow_phrases() {
+        let cases = [
+            ("x maps to y", "x↦y"),
+            ("x sends to y", "x↦y"),
+            ("f returns y", "f→y"),
+            ("f produces y", "f→y"),
+            ("A yields B", "A⊢B"),
+            ("A entails B", "A⊢B"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+
+        assert_eq!(RosettaStone::to_prose("x↦y"), "x maps to y");
+        assert_eq!(RosettaStone::convert("x maps to y").0, "x↦y");
+        assert_eq!(RosettaStone::to_prose("A⊢B"), "A proves B");
+        assert_eq!(RosettaStone::convert("A proves B").0, "A⊢B");
+    }
+
+    #[test]
+    fn test_convert_comparatives() {
+        // Off by default: the comparison stays prose rather than "A is >secure B"
+        let input = "A is more secure than B";
+        assert_eq!(RosettaStone::convert(input).0, input);
+
+        let options = RosettaOptions {
+            symbolize_comparatives: true,
+            ..Default::default()
+        };
+        let cases = [
+            ("A is more secure than B", "secure(A)>secure(B)"),
+            (
+                "the cache is less reliable than the disk",
+                "reliable(cache)<reliable(disk)",
+            ),
+            ("x is more than 5", "x>5"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                RosettaStone::convert_with(input, &options).0,
+                expected,
+                "{:?}",
+                input
+            );
+        }
+
+        assert_eq!(
+            RosettaStone::to_prose("secure(A)>secure(B)"),
+            "A is more secure than B"
+        );
+        assert_eq!(
+            RosettaStone::convert_with("A is more secure than B", &options).0,
+            "secure(A)>secure(B)"
+        );
+    }
+
+    #[test]
+    fn test_convert_bound_variables() {
+        let cases = [
+            ("for all x and y in S", "∀x,y∈S"),
+            ("for all x, y in S", "∀x,y∈S"),
+            ("for every a, b, and c in T", "∀a,b,c∈T"),
+            ("there exist p and q in P", "∃p,q∈P"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+            let prose = RosettaStone::to_prose(expected);
+            assert_eq!(RosettaStone::convert(&prose).0, expected, "{:?}", prose);
+        }
+
+        // Separate bindings and negated variables keep their conjunction
+        assert_eq!(
+            RosettaStone::convert("for all x in S and y in T").0,
+            "∀ x∈S∧y∈T"
+        );
+        assert!(RosettaStone::convert("for all x and not y in S")
+            .0
+            .contains('∧'));
+    }
+
+    #[test]
+    fn test_convert_imperative_quantifiers() {
+        let cases = [
+            ("For each x in S, check x", "∀x∈S:check(x)"),
+            (
+                "for every request in the queue, log it.",
+                "∀request∈queue:log(request).",
+            ),
+            ("for each job in jobs, do retry", "∀job∈jobs:retry(job)"),
+            (
+                "for each item in the list, validate it",
+                "∀item∈list:validate(item)",
+            ),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+            // The bound set stays a name when the output is converted again
+            assert_eq!(RosettaStone::convert(expected).0, expected);
+        }
+
+        // Statements about the variable are not actions
+        let (result, _, _) = RosettaStone::convert("for each x in S, x is greater than 0");
+        assert!(!result.contains('('), "{:?}", result);
+
+        assert_eq!(
+            RosettaStone::to_prose("∀item∈S:validate(item)"),
+            "for each item in S, validate it"
+        );
+        let (back, _, _) = RosettaStone::convert(&RosettaStone::to_prose("∀x∈S:check(x)"));
+        assert_eq!(back, "∀x∈S:check(x)");
+    }
+
+    #[test]
+    fn test_convert_conditions() {
+        let cases = [
+            ("for all x in S such that x is greater than 0", "∀x∈S∣x>0"),
+            ("there exists y in T where y is true", "∃y∈T∣y is ⊤"),
+            ("P(A given B)", "P(A∣B)"),
+            ("P(A conditioned on B)", "P(A∣B)"),
+            (
+                "store the row in the table where users are kept",
+                "store the row∈the table where users are kept",
+            ),
+            ("given the input, return x", "given the input, return x"),
+            ("pick x such that x is even", "pick x∣x is even"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{:?}", input);
+        }
+        assert_eq!(
+            RosettaStone::to_prose("if x then y∣z"),
+            "if x then y else z"
+        );
+
+        assert_eq!(
+            RosettaStone::to_prose("∀x∈S∣x>0"),
+            "for all x in S such that x greater than 0"
+        );
+        assert_eq!(RosettaStone::to_prose("P(A∣B)"), "P(A given B)");
+        for aisp in ["∀x∈S∣x>0", "P(A∣B)"] {
+            let (back, _, _) = RosettaStone::convert(&RosettaStone::to_prose(aisp));
+            assert_eq!(back.replace(' ', ""), aisp);
+        }
+    }
+
+    #[test]
+    fn test_convert_conditionals() {
+        let (result, _, _) = RosettaStone::convert("if x then y else z");
+        assert_eq!(result, "x⇒y∣¬x⇒z");
+        let (result, _, _) = RosettaStone::convert("If x is greater than 5 then y, else z");
+        assert_eq!(result, "x>5⇒y∣¬(x>5)⇒z");
+
+        let prose = RosettaStone::to_prose("x⇒y∣¬x⇒z");
+        assert_eq!(prose, "if x then y else z");
+        let prose = RosettaStone::to_prose("x>5⇒y∣¬(x>5)⇒z");
+        assert!(prose.starts_with("if x greater than 5 then y else z"));
+
+        // Branches guarded by unrelated conditions are left alone
+        let prose = RosettaStone::to_prose("x⇒y∣¬w⇒z");
+        assert!(!prose.contains("if"));
+
+        // "else" is a conditional keyword, not the reading of a bare bar
+        assert_eq!(symbol_to_prose("∣"), Some("such that"));
+        assert_eq!(RosettaStone::to_prose("x∣y"), "x such that y");
+        assert_eq!(RosettaStone::convert("else").0, "else");
+    }
+
+    #[test]
+    fn test_convert_negated_quantifiers() {
+        for (input, expected) in [
+            ("not every user is admin", "¬∀user is admin"),
+            ("not all x in S", "¬∀x∈S"),
+            ("there is no admin", "∄admin"),
+            ("there is no user", "∄user"),
+            ("there does not exist a solution", "∄solution"),
+        ] {
+            assert_eq!(RosettaStone::convert(input).0, expected, "{}", input);
+        }
+
+        assert_eq!(RosettaStone::to_prose("¬∀x∈S"), "not for all x in S");
+    }
+
+    #[test]
+    fn test_convert_negation_contractions() {
+        for input in [
+            "the user won't login",
+            "the user won’t login",
+            "the user can't login",
+            "the user shouldn’t login",
+            "the users aren't admins",
+            "the user wasn’t admin",
+        ] {
+            let (result, _, _) = RosettaStone::convert(input);
+            assert!(result.contains("¬"), "{} → {}", input, result);
+        }
+        assert_eq!(RosettaStone::convert("the user won't login").0, "the user ¬login");
+        assert_eq!(RosettaStone::convert("the user won’t login").0, "the user ¬login");
+        assert_eq!(RosettaStone::convert("the user isn't admin").0, "the user ¬admin");
+
+        // Possessives are left alone
+        let (result, _, _) = RosettaStone::convert("the admin's flag isn’t set");
+        assert!(result.contains("admin's"));
+        assert!(result.contains("¬"));
+        assert_eq!(prose_to_symbol("won’t"), Some("¬"));
+    }
+
+    #[test]
+    fn test_lookup_maps() {
+        assert_eq!(RosettaStone::pattern_map().get("for all"), Some(&"∀"));
+        assert_eq!(RosettaStone::symbol_map().get("∀"), Some(&"for all"));
+        for (symbol, pattern) in RosettaStone::symbol_map() {
+            assert_eq!(symbol_to_prose(symbol), Some(*pattern));
+        }
+    }
+
+    #[test]
+    fn test_singularize() {
+        assert_eq!(
+            RosettaStone::singularize("booleans and complex numbers"),
+            "boolean and complex number"
+        );
+        assert_eq!(
+            RosettaStone::singularize("Lists of matches"),
+            "List of matches"
+        );
+        // Plurals already listed, and words that only look plural, are untouched
+        assert_eq!(RosettaStone::singularize("integers"), "integers");
+        assert_eq!(RosettaStone::singularize("access is yes"), "access is yes");
+    }
+
+    #[test]
+    fn test_split_concatenated() {
+        assert_eq!(
+            RosettaStone::split_concatenated("x GreaterThanOrEqual 5 and y lessthan 3"),
+            "x greater than or equal 5 and y less than 3"
+        );
+        assert_eq!(
+            RosettaStone::split_concatenated("a ifandonlyif b"),
+            "a if and only if b"
+        );
+        // Only whole words, and never short joins that read as words
+        assert_eq!(
+            RosettaStone::split_concatenated("greaterthanx isa isin"),
+            "greaterthanx isa isin"
+        );
+    }
+
+    #[test]
+    fn test_normalize_numerals() {
+        let cases = [
+            ("three users", "3 users"),
+            (
+                "twenty-one items and ninety nine more",
+                "21 items and 99 more",
+            ),
+            ("a hundred users", "100 users"),
+            ("the two hundred thirty-fourth entry", "the 234th entry"),
+            ("the twelfth user", "the 12th user"),
+            ("Nineteen cats", "19 cats"),
+            // Table patterns and pronouns keep their words
+            ("exactly one user", "exactly one user"),
+            ("there is at least one user", "there is at least one user"),
+            ("a two-way link", "a two-way link"),
+            ("no one knows", "no one knows"),
+            // Ordinals that are not modifying a word
+            ("First, define x", "First, define x"),
+            ("wait a second please", "wait a second please"),
+            ("five second timeout", "5 second timeout"),
+            ("x is the second", "x is the second"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(
+                RosettaStone::normalize_numerals(input),
+                expected,
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_tokenize() {
+        assert_eq!(
+            RosettaStone::tokenize("∀x∈S: ∃!y≜f(x)"),
+            vec!["∀", "x", "∈", "S", ":", "∃!", "y", "≜", "f", "(", "x", ")"]
+        );
+        assert_eq!(RosettaStone::tokenize("⟦Ω⟧ ◊⁺⁺"), vec!["⟦Ω⟧", "◊⁺⁺"]);
+    }
+
+    #[test]
+    fn test_convert_ascii_operators() {
+        for (input, expected) in [
+            ("x >= 5", "x≥5"),
+            ("x <= 5", "x≤5"),
+            ("x === y", "x≡y"),
+            ("x == y", "x≡y"),
+            ("x !== y", "x≢y"),
+            ("x != y", "x≢y"),
+            ("x := 5", "x≔5"),
+            ("x => y", "x λ y"),
+        ] {
+            let (result, mapped, _) = RosettaStone::convert(input);
+            assert_eq!(result, expected, "{:?}", input);
+            assert!(mapped > 0);
+        }
+
+        // ASCII aliases and word phrasing produce the same form
+        assert_eq!(RosettaStone::convert("x >= 5 and y != 3").0, "x≥5∧y≢3");
+        assert_eq!(RosettaStone::convert("x is at least 5").0, "x≥5");
+
+        // Unspaced operators convert too
+        assert_eq!(RosettaStone::convert("x>=5").0, "x≥5");
+    }
+
+    #[test]
+    fn test_convert_basic() {
+        let (result, _, _) = RosettaStone::convert("for all x in S");
+        assert!(result.contains("∀"));
+        assert!(result.contains("∈"));
+    }
+
+    #[test]
+    fn test_convert_by_category() {
+        let (_, category_chars, _) = RosettaStone::convert_by_category("for all x in S and y");
+        assert_eq!(category_chars.get("quantifier"), Some(&7));
+        assert_eq!(category_chars.get("set"), Some(&2));
+        assert_eq!(category_chars.get("logic"), Some(&3));
+
+        let breakdown = RosettaStone::category_confidence(20, &category_chars);
+        assert_eq!(breakdown.get("quantifier"), Some(&0.35));
+        let total: f64 = breakdown.values().sum();
+        assert!((total - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_convert_assignment() {
+        let (result, _, _) = RosettaStone::convert("Define x as 5");
+        assert!(result.contains("≜"));
+    }
+
+    #[test]
+    fn test_entries_by_category() {
+        let entries = entries_by_category("truth");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].symbol, "⊤");
+        assert!(entries[0].patterns.contains(&"true"));
+        assert_eq!(symbols_by_category("truth"), vec!["⊤", "⊥"]);
+        assert!(entries_by_category("unknown").is_empty());
+    }
+
+    #[test]
+    fn test_category_of() {
+        assert_eq!(category_of("∈"), Some("set"));
+        assert_eq!(category_of("≜"), Some("definition"));
+        assert_eq!(category_of("μ"), Some("function"));
+        assert_eq!(category_of("unknown"), None);
+
+        for entry in ROSETTA {
+            assert!(symbols_by_category(category_of(entry.symbol).unwrap()).contains(&entry.symbol));
+        }
+    }
+
+    #[test]
+    fn test_bands() {
+        assert_eq!(band_of("∧").unwrap().name, "Transmuters");
+        assert_eq!(band_of("≜").unwrap().glyph, "Ω");
+        assert_eq!(band_of("ℕ").unwrap().to_string(), "Domaines[256-319]");
+        assert_eq!(band_of("⟦Ω⟧").unwrap().start, 384);
+        assert!(band_of("+").is_none());
+
+        // Bands tile the glossary without gaps
+        for pair in AISP_BANDS.windows(2) {
+            assert_eq!(pair[0].end + 1, pair[1].start);
         }
+    }
 
-        // Convert "Define x as y" to "x≜y"
-        if let Ok(regex) = Regex::new(r"(?i)Define\s+(\w+)\s+as\s+(\S+)") {
-            result = regex.replace_all(&result, "$1≜$2").to_string();
+    #[test]
+    fn test_mapping_count() {
+        assert!(get_mapping_count() > 300);
+    }
+
+    #[test]
+    fn test_mapping_counts_by_category() {
+        let counts = mapping_counts_by_category();
+        assert_eq!(counts.len(), get_all_categories().len());
+
+        let quantifier = counts["quantifier"];
+        assert_eq!(quantifier.entries, symbols_by_category("quantifier").len());
+        assert!(quantifier.patterns > quantifier.entries);
+
+        let total: usize = counts.values().map(|c| c.patterns).sum();
+        assert_eq!(total, get_mapping_count());
+    }
+
+    #[test]
+    fn test_preview() {
+        let input = "for all x in S, x is greater than or equal to y";
+        let planned = RosettaStone::preview(input);
+
+        let phrases: Vec<_> = planned.iter().map(|p| &input[p.span.clone()]).collect();
+        assert_eq!(phrases, vec!["greater than or equal to", "in", "for all"]);
+        assert_eq!(planned[0].symbol, "≥");
+        assert_eq!(planned[2].pattern, "for all");
+
+        // Escaped spans are skipped; a hint is planned whole, ahead of the table
+        let input = r"\aisp{for all x} and x [[⊆:is contained in]] T";
+        let planned = RosettaStone::preview(input);
+        let phrases: Vec<_> = planned.iter().map(|p| &input[p.span.clone()]).collect();
+        assert_eq!(phrases, vec!["[[⊆:is contained in]]", "and"]);
+        assert_eq!(planned[0].symbol, "⊆");
+    }
+
+    #[test]
+    fn test_preview_agrees_with_convert() {
+        for (input, expected) in [
+            ("x is between 1 and 10", "1≤x≤10"),
+            ("for all x and y in S", "∀x,y∈S"),
+            ("the following: a, b, and c", "{a,b,c}"),
+        ] {
+            assert_eq!(RosettaStone::convert(input).0, expected);
+            let planned = RosettaStone::preview(input);
+            assert!(
+                planned.iter().all(|p| p.symbol != "∧"),
+                "{:?}: {:?}",
+                input,
+                planned
+            );
+            for p in &planned {
+                assert!(expected.contains(p.symbol), "{:?}: {:?}", input, p);
+            }
         }
 
-        // Convert "let x = y" to "x≜y"
-        if let Ok(regex) = Regex::new(r"(?i)let\s+(\w+)\s*=\s*(\S+)") {
-            result = regex.replace_all(&result, "$1≜$2").to_string();
+        // Phrases outside the rewritten text are still planned
+        let input = "x is between 1 and 10 and y is true";
+        let planned = RosettaStone::preview(input);
+        let phrases: Vec<_> = planned.iter().map(|p| &input[p.span.clone()]).collect();
+        assert_eq!(phrases, vec!["and", "true"]);
+        assert_eq!(planned[0].span, 22..25);
+        assert_eq!(RosettaStone::convert(input).0, "1≤x≤10∧y is ⊤");
+    }
+
+    #[test]
+    fn test_conversion_legend() {
+        let legend =
+            RosettaStone::conversion_legend("For all x in S and for all y in T, x [[⊆:within]] y");
+        assert_eq!(
+            legend
+                .iter()
+                .filter(|(_, symbol, _)| *symbol == "∈")
+                .count(),
+            1
+        );
+        // Case differences are distinct phrases
+        assert!(legend.contains(&("For all".to_string(), "∀", "quantifier")));
+        assert!(legend.contains(&("for all".to_string(), "∀", "quantifier")));
+        assert!(legend.contains(&("within".to_string(), "⊆", "set")));
+        assert!(legend.contains(&("and".to_string(), "∧", "logic")));
+
+        assert!(RosettaStone::conversion_legend("lorem ipsum").is_empty());
+    }
+
+    #[test]
+    fn test_unmapped_excludes_identifiers() {
+        let input = "count becomes 5 and userId is valid when blorp";
+        let (_, _, unmapped) = RosettaStone::convert(input);
+        assert!(unmapped.contains(&"count".to_string()));
+        assert!(unmapped.contains(&"userid".to_string()));
+
+        let options = RosettaOptions {
+            exclude_identifiers: true,
+            ..Default::default()
+        };
+        let (result, _, unmapped) = RosettaStone::convert_with(input, &options);
+        assert!(result.starts_with("count≔5"));
+        // Bound by ≔, and camelCase
+        assert!(!unmapped.contains(&"count".to_string()));
+        assert!(!unmapped.contains(&"userid".to_string()));
+        // Plain prose still shows up
+        assert!(unmapped.contains(&"blorp".to_string()));
+    }
+
+    #[test]
+    fn test_validate_entry() {
+        for entry in ROSETTA {
+            assert_eq!(
+                RosettaStone::validate_entry(entry),
+                Ok(()),
+                "{}",
+                entry.symbol
+            );
         }
 
-        result
+        let entry = |symbol: &'static str, patterns: &'static [&'static str]| RosettaEntry {
+            symbol,
+            patterns,
+            category: "custom",
+        };
+        assert_eq!(
+            RosettaStone::validate_entry(&entry("", &["x"])),
+            Err(EntryError::EmptySymbol)
+        );
+        assert!(matches!(
+            RosettaStone::validate_entry(&entry("a b", &["x"])),
+            Err(EntryError::WhitespaceInSymbol { .. })
+        ));
+        assert!(matches!(
+            RosettaStone::validate_entry(&entry("$1", &["x"])),
+            Err(EntryError::DollarInSymbol { .. })
+        ));
+        assert!(matches!(
+            RosettaStone::validate_entry(&entry("⊙", &["dot", " "])),
+            Err(EntryError::EmptyPattern { .. })
+        ));
+        assert!(matches!(
+            RosettaStone::validate_entry(&entry("⊙", &["dot", "Dot"])),
+            Err(EntryError::DuplicatePattern { .. })
+        ));
+        // Nested patterns within one entry are fine
+        assert_eq!(
+            RosettaStone::validate_entry(&entry("⊙", &["dot", "dot product"])),
+            Ok(())
+        );
     }
 
-    /// Find words that weren't mapped to symbols
-    fn find_unmapped_words(result: &str) -> Vec<String> {
-        let ignore_words = [
-            "the", "with", "that", "this", "from", "into", "when", "where", "which", "what",
+    #[test]
+    fn test_validate_table() {
+        assert_eq!(RosettaStone::validate_table(crate::lang::SPANISH), Ok(()));
+        assert!(RosettaStone::validate_table(ROSETTA).is_ok());
+        assert!(CompiledTable::try_new(ROSETTA).is_ok());
+
+        static SHADOWED: &[RosettaEntry] = &[
+            RosettaEntry {
+                symbol: "∃",
+                patterns: &["there exists", "some"],
+                category: "quantifier",
+            },
+            RosettaEntry {
+                symbol: "∃!",
+                patterns: &["exactly one"],
+                category: "quantifier",
+            },
+            RosettaEntry {
+                symbol: "⊙",
+                patterns: &["some product"],
+                category: "custom",
+            },
         ];
+        let err = RosettaStone::validate_table(SHADOWED).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\"some\" (∃) matches first inside \"some product\" (⊙)"
+        );
+    }
 
-        let word_regex = Regex::new(r"\b[a-zA-Z]{3,}\b").unwrap();
-        let words: Vec<_> = word_regex
-            .find_iter(result)
-            .map(|m| m.as_str().to_lowercase())
-            .collect();
+    #[test]
+    fn test_weighted_confidence() {
+        let input = "x in S";
+        let (_, subs, _) = RosettaStone::convert_detailed(input, &RosettaOptions::default());
+        let plain = RosettaStone::confidence(input.len(), 2);
+        assert_eq!(
+            RosettaStone::weighted_confidence(input.len(), &subs),
+            plain * 0.5
+        );
 
-        let mut unique: Vec<_> = words
+        let input = "p if and only if q";
+        let (_, subs, _) = RosettaStone::convert_detailed(input, &RosettaOptions::default());
+        assert!(RosettaStone::weighted_confidence(input.len(), &subs) > 0.99);
+        assert_eq!(RosettaStone::weighted_confidence(0, &[]), 1.0);
+    }
+
+    #[test]
+    fn test_lossless_mode() {
+        let options = RosettaOptions {
+            mode: ConversionMode::Lossless,
+            ..Default::default()
+        };
+        let input = "for all x in S, f returns x and g yields y";
+        let (output, _, _) = RosettaStone::convert_with(input, &options);
+        assert!(output.contains("returns"), "{}", output);
+        assert!(output.contains("yields"), "{}", output);
+        assert!(output.starts_with("∀ x∈S"), "{}", output);
+
+        // Whatever lossless mode keeps comes back as the same phrase
+        let prose = RosettaStone::to_prose(&output);
+        assert_eq!(RosettaStone::convert_with(&prose, &options).0, output);
+
+        let lossy: Vec<_> = RosettaStone::lossy_substitutions(input)
             .into_iter()
-            .filter(|w| !ignore_words.contains(&w.as_str()))
+            .map(|sub| (sub.matched, sub.symbol))
             .collect();
+        assert_eq!(lossy.len(), 2);
+        assert!(lossy.contains(&("returns".to_string(), "→")));
+        assert!(lossy.contains(&("yields".to_string(), "⊢")));
 
-        unique.sort();
-        unique.dedup();
-        unique
+        // μ is shared by two entries, so neither reverses uniquely
+        assert!(!LOSSLESS_TABLE.iter().any(|entry| entry.symbol == "μ"));
+
+        // Phrase passes don't run, and longer phrases aren't split by a primary inside them
+        for input in [
+            "a function from A to B",
+            "the ratio of a to b",
+            "for every integer n greater than zero",
+            "x is greater than or equal to 5",
+            "For all x in S",
+        ] {
+            let (output, _, _) = RosettaStone::convert_with(input, &options);
+            assert!(!output.contains('∀') && !output.contains(':'), "{}", output);
+            assert!(!output.contains("≥→"), "{}", output);
+        }
     }
 
-    /// Convert AISP symbols back to prose
-    /// Maintains spacing for readability while preserving semantic meaning
-    pub fn to_prose(input: &str) -> String {
-        let mut result = input.to_string();
+    #[test]
+    fn test_lossless_round_trip() {
+        let options = RosettaOptions {
+            mode: ConversionMode::Lossless,
+            ..Default::default()
+        };
+        let corpus = [
+            "for all x in S, x is valid",
+            "there exists x in S such that x is even",
+            "x and y or not z",
+            "A implies B",
+            "f maps A to B; g is true or false",
+            "x is greater than or equal to 5",
+            "x is greater than or equal 5",
+            "x is between 1 and 10",
+            "a function from A to B",
+            "the ratio of a to b",
+            "for every integer n greater than zero",
+            "the size of the file is large",
+            "the probability of rain given clouds",
+            "x must be at least 5 seconds",
+            "if the user is an admin then allow access",
+            "For all users, f returns a list of strings",
+            "x squared plus y",
+        ];
+        for input in corpus {
+            let (output, _, _) = RosettaStone::convert_with(input, &options);
+            assert_eq!(RosettaStone::to_prose(&output), input, "{:?}", output);
+        }
+    }
 
-        // Sort by symbol length (longest first) to avoid partial replacements
-        let mut entries: Vec<_> = ROSETTA.iter().collect();
-        entries.sort_by(|a, b| b.symbol.len().cmp(&a.symbol.len()));
+    #[test]
+    fn test_sanitize_typography() {
+        let (result, _, _) = RosettaStone::convert("x\u{00A0}and\u{00A0}y");
+        assert_eq!(result, "x∧y");
+        let (result, _, _) = RosettaStone::convert("“for all” x in S");
+        assert_eq!(result, "\"∀\" x∈S");
+        assert_eq!(RosettaStone::sanitize("5–10 — see…"), "5-10 - see...");
+        assert_eq!(RosettaStone::sanitize("a\u{200B}b − c"), "ab − c");
 
-        for entry in entries {
-            if let Some(primary) = entry.patterns.first() {
-                // Add spaces around word replacements for readability
-                let replacement = format!(" {} ", primary);
-                result = result.replace(entry.symbol, &replacement);
-            }
-        }
+        // Escaped spans and opted-out conversions keep their typography
+        let (result, _, _) = RosettaStone::convert(r"\aisp{“q”} and y");
+        assert_eq!(result, r"\aisp{“q”}∧y");
+        let options = RosettaOptions {
+            preserve_typography: true,
+            ..Default::default()
+        };
+        let (result, _, _) = RosettaStone::convert_with("“x” and y", &options);
+        assert_eq!(result, "“x”∧y");
+    }
 
-        // Ensure spaces between letters that got concatenated
-        // Handles cases like "adminimpliesallow" → "admin implies allow"
-        result = Self::add_word_boundaries(&result);
+    #[test]
+    fn test_preserve_symbols_pass() {
+        let options = RosettaOptions {
+            preserve_symbols: true,
+            ..Default::default()
+        };
+        let (output, subs, _) = RosettaStone::convert_detailed("∀ x∈ℤ and y is true", &options);
+        assert_eq!(output, "∀ x∈ℤ∧y is ⊤");
+        assert!(subs
+            .iter()
+            .all(|sub| sub.symbol != "∀" && sub.symbol != "∈"));
 
-        // Clean up multiple spaces and trim
-        Self::normalize_whitespace(&result)
+        // Trace snapshots show the symbols, not their placeholders
+        let mut trace = Vec::new();
+        RosettaStone::run_pipeline(
+            "x∈S or y",
+            &options,
+            ROSETTA,
+            &RegexEngine,
+            Some(&mut trace),
+        );
+        assert!(trace
+            .iter()
+            .all(|snapshot| snapshot.output.starts_with("x∈S")));
     }
 
-    /// Add spaces between concatenated words
-    fn add_word_boundaries(input: &str) -> String {
-        // Add space between lowercase followed by uppercase
-        let camel_case = Regex::new(r"([a-z])([A-Z])").unwrap();
-        let result = camel_case.replace_all(input, "$1 $2");
+    #[test]
+    fn test_convert_traced() {
+        let input = r"set x to 5 and \aisp{and}";
+        let (output, stages) = RosettaStone::convert_traced(input);
+        assert_eq!(output, RosettaStone::convert(input).0);
 
-        // Add space before words that follow certain patterns
-        let word_join = Regex::new(r"([a-zA-Z])( )(for all|exists|implies|and|or|not|if|then|else|in|defined as|identical to|true|false|lambda|function|returns|boolean|integer|string|natural|real|proves|therefore|yields)( )").unwrap();
-        let result = word_join.replace_all(&result, "$1 $3 ");
+        let names: Vec<_> = stages.iter().map(|s| s.stage).collect();
+        assert_eq!(names.first(), Some(&"hints"));
+        assert_eq!(names.last(), Some(&"groups"));
+        assert!(names.contains(&"assignments"));
 
-        result.to_string()
+        // Snapshots show escapes restored, never placeholders
+        assert!(stages.iter().all(|s| s.output.contains(r"\aisp{and}")));
+        assert_eq!(stages.last().unwrap().output.trim(), output);
     }
 
-    /// Normalize whitespace in text
-    fn normalize_whitespace(input: &str) -> String {
-        let multiple_spaces = Regex::new(r"\s+").unwrap();
-        let result = multiple_spaces.replace_all(input, " ");
+    #[test]
+    fn test_coverage_gaps() {
+        let gaps = RosettaStone::coverage_gaps("for all widgets in S, widgets glow");
+        assert_eq!(gaps[0], ("widgets".to_string(), 14));
+        assert!(gaps.contains(&("glow".to_string(), 4)));
+        assert!(RosettaStone::coverage_gaps("for all x in S").is_empty());
+    }
 
-        // Clean up spaces around punctuation
-        let space_before_punct = Regex::new(r"\s+([.,;:!?])").unwrap();
-        let result = space_before_punct.replace_all(&result, "$1");
+    #[test]
+    fn test_suggest_mappings() {
+        let corpus = [
+            "the cache stores x and caching is fast",
+            "logging is on, the logger caches y",
+            "if caches are full, log z",
+        ];
+        let suggestions = RosettaStone::suggest_mappings(&corpus);
+        let cache = &suggestions[0];
+        assert_eq!(cache.word, "caches");
+        assert_eq!(cache.variants, vec!["cache", "caching"]);
+        assert_eq!(cache.occurrences, 4);
+        assert_eq!(cache.documents, 3);
 
-        // Clean up spaces after opening brackets
-        let space_after_open = Regex::new(r"([(\[{])\s+").unwrap();
-        let result = space_after_open.replace_all(&result, "$1");
+        let log = suggestions.iter().find(|s| s.word == "log").unwrap();
+        assert_eq!(log.variants, vec!["logger", "logging"]);
+        assert_eq!(log.documents, 2);
 
-        // Clean up spaces before closing brackets
-        let space_before_close = Regex::new(r"\s+([)\]}])").unwrap();
-        let result = space_before_close.replace_all(&result, "$1");
+        assert!(!suggestions.iter().any(|s| s.word == "and"));
+        assert!(RosettaStone::suggest_mappings(&["for all x in S"]).is_empty());
+        assert!(RosettaStone::suggest_mappings(&[]).is_empty());
+    }
 
-        result.trim().to_string()
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_table_json_round_trip() {
+        let json = serde_json::to_string(ROSETTA).unwrap();
+        let loaded = RosettaStone::load_table(&json).unwrap();
+        assert_eq!(loaded.len(), ROSETTA.len());
+        assert_eq!(loaded[0], OwnedRosettaEntry::from(&ROSETTA[0]));
+
+        let entries: Vec<_> = loaded.into_iter().map(|e| e.into_static()).collect();
+        let table = CompiledTable::new(&entries);
+        let (output, _, _) = RosettaStone::convert_with_table("for all x in S", Some(&table));
+        assert_eq!(output, RosettaStone::convert("for all x in S").0);
+
+        assert!(RosettaStone::load_table(r#"[{"symbol": "⊕"}]"#).is_err());
     }
 
-    /// Normalize text for semantic comparison (removes formatting differences)
-    pub fn normalize_for_comparison(input: &str) -> String {
-        let lowercase = input.to_lowercase();
-        let normalized = Self::normalize_whitespace(&lowercase);
+    #[cfg(feature = "fuzzy")]
+    #[test]
+    fn test_fuzzy_candidates() {
+        let (_, _, unmapped) = RosettaStone::convert("x impies y is boolenn and widget");
+        let candidates = RosettaStone::fuzzy_candidates(&unmapped);
 
-        // Remove punctuation for semantic comparison
-        let punct_regex = Regex::new(r#"[.,;:!?"']"#).unwrap();
-        punct_regex.replace_all(&normalized, "").trim().to_string()
+        assert!(candidates.contains(&("impies".to_string(), "implies")));
+        assert!(candidates.contains(&("boolenn".to_string(), "boolean")));
+        assert!(!candidates.iter().any(|(w, _)| w == "widget"));
+
+        assert!(within_one_edit("graph", "graphs"));
+        assert!(within_one_edit("grapf", "graph"));
+        assert!(!within_one_edit("grpfh", "graph"));
     }
 
-    /// Check semantic equivalence between two texts
-    /// Returns similarity score from 0.0 to 1.0
-    pub fn semantic_similarity(text1: &str, text2: &str) -> f64 {
-        let norm1 = Self::normalize_for_comparison(text1);
-        let norm2 = Self::normalize_for_comparison(text2);
+    #[test]
+    fn test_escaped_spans_survive_conversion() {
+        // Prose that talks about the patterns themselves
+        let (result, _, unmapped) =
+            RosettaStone::convert(r"the phrase \aisp{for all} becomes ∀, so for all x in S");
+        assert!(result.starts_with(r"the phrase \aisp{for all}"));
+        assert!(result.ends_with("∀ x∈S"));
+        assert!(!unmapped.contains(&"aisp".to_string()));
 
-        // Extract words
-        let words1: HashSet<_> = norm1.split_whitespace().collect();
-        let words2: HashSet<_> = norm2.split_whitespace().collect();
+        // AISP that quotes the symbols themselves
+        let prose = RosettaStone::to_prose(r"\aisp{∀x∈S} reads as ∀x∈S");
+        assert!(prose.starts_with(r"\aisp{∀x∈S} reads as for all"));
 
-        if words1.is_empty() && words2.is_empty() {
-            return 1.0;
-        }
+        // Many spans keep their own contents
+        let spans: String = (0..12).map(|i| format!(r"\aisp{{s{}∧}} ", i)).collect();
+        let input = format!("{}x∧y", spans);
+        assert_eq!(RosettaStone::to_prose(&input), format!("{}x and y", spans));
+    }
 
-        // Jaccard similarity
-        let intersection = words1.intersection(&words2).count();
-        let union = words1.union(&words2).count();
+    #[test]
+    fn test_conversion_hints() {
+        // "contained in" alone would not map; the hint forces ⊆
+        let (result, mapped, unmapped) = RosettaStone::convert("R [[⊆:is contained in]] T");
+        assert_eq!(result, "R ⊆ T");
+        assert_eq!(mapped, "is contained in".len());
+        assert!(!unmapped.contains(&"contained".to_string()));
 
-        if union == 0 {
-            1.0
-        } else {
-            intersection as f64 / union as f64
-        }
+        // The hint overrides the automatic "and" → ∧
+        let (result, _, _) = RosettaStone::convert("x [[∩:and]] y and z");
+        assert_eq!(result, "x ∩ y∧z");
+
+        // Hints inside escapes stay verbatim
+        let (result, _, _) = RosettaStone::convert(r"\aisp{[[⊆:in]]}");
+        assert_eq!(result, r"\aisp{[[⊆:in]]}");
+
+        assert_eq!(
+            RosettaStone::to_prose("R [[⊆:is contained in]] T"),
+            "R is contained in T"
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_to_prose_capitalize_sentences() {
+        let options = ProseOptions {
+            capitalize_sentences: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            RosettaStone::to_prose_with("∀x∈S:x>0. ¬y. x≜5", &options),
+            "For all x in S:x greater than 0. Not y. x defined as 5"
+        );
+        assert_eq!(
+            RosettaStone::to_prose_with(
+                "∃x:P",
+                &ProseOptions {
+                    style: ProseStyle::Natural,
+                    ..options
+                }
+            ),
+            "There exists an x such that P"
+        );
+        // Off by default
+        assert_eq!(RosettaStone::to_prose("¬y"), "not y");
+    }
 
     #[test]
-    fn test_prose_to_symbol() {
-        assert_eq!(prose_to_symbol("for all"), Some("∀"));
-        assert_eq!(prose_to_symbol("exists"), Some("∃"));
-        assert_eq!(prose_to_symbol("unknown"), None);
+    fn test_to_prose_natural_style() {
+        assert_eq!(
+            RosettaStone::to_prose_with_style("∀x∈S:P", ProseStyle::Natural),
+            "for all x in S, P holds"
+        );
+        assert_eq!(
+            RosettaStone::to_prose_with_style("∃x:P", ProseStyle::Natural),
+            "there exists an x such that P"
+        );
+        // Unique existence keeps its own phrasing
+        assert!(!RosettaStone::to_prose_with_style("∃!x:P", ProseStyle::Natural).contains(" an "));
+        // Terse remains the default
+        assert_eq!(
+            RosettaStone::to_prose("∀x∈S:P"),
+            RosettaStone::to_prose_with_style("∀x∈S:P", ProseStyle::Terse)
+        );
+        assert!(!RosettaStone::to_prose("∀x∈S:P").contains("holds"));
     }
 
     #[test]
-    fn test_convert_basic() {
-        let (result, _, _) = RosettaStone::convert("for all x in S");
-        assert!(result.contains("∀"));
-        assert!(result.contains("∈"));
+    fn test_prose_overrides_round_trip() {
+        let overrides = ProseOverrides::new()
+            .with("≜", "Means")
+            .unwrap()
+            .with("∀", "for every")
+            .unwrap();
+        assert_eq!(overrides.get("≜"), Some("means"));
+
+        let options = ProseOptions {
+            overrides,
+            ..Default::default()
+        };
+        let prose = RosettaStone::to_prose_with("∀x∈S:x≜5", &options);
+        assert_eq!(prose, "for every x in S:x means 5");
+        assert!(RosettaStone::convert(&prose).0.contains("x≜5"));
+
+        let err = ProseOverrides::new().with("≜", "is").unwrap_err();
+        assert_eq!(err.symbol, "≜");
+        assert!(ProseOverrides::new().with("nope", "for all").is_err());
     }
 
     #[test]
-    fn test_convert_assignment() {
-        let (result, _, _) = RosettaStone::convert("Define x as 5");
-        assert!(result.contains("≜"));
+    fn test_split_sentences() {
+        assert_eq!(
+            RosettaStone::split_sentences("x is 0.5. Use e.g. y etc. here! J. Doe wrote it?\nDone"),
+            vec![
+                "x is 0.5.",
+                "Use e.g. y etc. here!",
+                "J. Doe wrote it?",
+                "Done"
+            ]
+        );
+        assert!(RosettaStone::split_sentences("  \n ").is_empty());
     }
 
     #[test]
-    fn test_mapping_count() {
-        assert!(get_mapping_count() > 300);
+    fn test_is_aisp() {
+        assert!(RosettaStone::is_aisp("∀ x∈S"));
+        assert!(RosettaStone::is_aisp("x≥5"));
+        assert!(RosettaStone::is_aisp("𝔸1.0.spec@2026-01-01\n⟦Ω:Meta⟧{}"));
+        assert!(!RosettaStone::is_aisp("for all x in S"));
+        assert!(!RosettaStone::is_aisp("the ∀ symbol is read as for all"));
+        assert!(!RosettaStone::is_aisp(""));
+    }
+
+    #[test]
+    fn test_escape_unescape() {
+        let escaped = RosettaStone::escape(r"{x} \ ∀");
+        assert_eq!(escaped, r"\aisp{{x\} \\ ∀}");
+        assert_eq!(RosettaStone::unescape(&escaped), r"{x} \ ∀");
+
+        let (converted, _, _) = RosettaStone::convert(&format!("{} and y", escaped));
+        assert_eq!(RosettaStone::unescape(&converted), r"{x} \ ∀∧y");
+    }
+
+    #[test]
+    fn test_to_prose_matches_sequential_replacement() {
+        // The pre-automaton expansion: one `replace` per entry, longest symbol first,
+        // with word symbols replaced as whole words
+        fn sequential(input: &str) -> String {
+            let mut entries: Vec<_> = ROSETTA.iter().collect();
+            entries.sort_by_key(|e| std::cmp::Reverse(e.symbol.len()));
+            let mut result = input.to_string();
+            for entry in entries {
+                if let Some(phrase) = entry.patterns.first() {
+                    let phrase = format!(" {} ", phrase);
+                    result = if entry.symbol.bytes().all(|b| b.is_ascii_alphabetic()) {
+                        let word = Regex::new(&format!(r"\b{}\b", entry.symbol)).unwrap();
+                        word.replace_all(&result, phrase.as_str()).to_string()
+                    } else {
+                        result.replace(entry.symbol, &phrase)
+                    };
+                }
+            }
+            result
+        }
+
+        // Spaced so "²³" is not read as one exponent
+        let symbols = ROSETTA
+            .iter()
+            .map(|e| e.symbol)
+            .collect::<Vec<_>>()
+            .join(" ");
+        for input in [
+            "∃!x∈ℕ:x²≡4",
+            "¬∀x∈S:P(x)⇒◊⁺⁺",
+            "Maybe(Either)∘fix→List",
+            "⟦Ω⟧⟦Σ⟧⟨a⟩",
+            symbols.as_str(),
+        ] {
+            assert_eq!(
+                RosettaStone::normalize_whitespace(&RosettaStone::expand_symbols(
+                    input,
+                    &ProseOptions::default()
+                )),
+                RosettaStone::normalize_whitespace(&sequential(input)),
+                "input {:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_prose_idempotent() {
+        // Nothing to expand: not even spacing changes
+        for prose in [
+            "userId is  set , ok .",
+            "80 percent of users",
+            "camelCaseName",
+            "Meeting at 10:30",
+            "3/4 done",
+            "50% done",
+            "see model.rs",
+        ] {
+            assert_eq!(RosettaStone::to_prose(prose), prose);
+        }
+
+        // Quantities read the same beside other symbols; only an operator's operand is phrased
+        assert_eq!(RosettaStone::to_prose("3/4 done∧x"), "3/4 done and x");
+        assert_eq!(RosettaStone::to_prose("50% done∧x"), "50% done and x");
+        assert_eq!(
+            RosettaStone::to_prose("ratio≤3/4∧x≥50%"),
+            "ratio less than or equal 3 out of 4 and x greater than or equal 50 percent"
+        );
+
+        for input in [
+            "for all x in S, x is greater than 0",
+            "if x then y else z",
+            "the number of elements in S is at most 10",
+            "at least two of the checks pass",
+            "x modulo 7 equals n factorial",
+            "x is at most 3 out of 4 and 50% done",
+        ] {
+            let (aisp, _, _) = RosettaStone::convert(input);
+            for style in [ProseStyle::Terse, ProseStyle::Natural] {
+                let once = RosettaStone::to_prose_with_style(&aisp, style);
+                assert_eq!(
+                    RosettaStone::to_prose_with_style(&once, style),
+                    once,
+                    "{:?}",
+                    aisp
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_to_prose_arithmetic_spacing() {
+        assert_eq!(RosettaStone::to_prose("3×4+2"), "3 times 4 plus 2");
+        assert_eq!(RosettaStone::to_prose("a−b"), "a minus b");
+        assert_eq!(RosettaStone::to_prose("x−(−1)"), "x minus (minus 1)");
+        assert_eq!(
+            RosettaStone::to_prose("result≔(a+b)÷2"),
+            "result assigned (a plus b) divided by 2"
+        );
+        // Operands keep their own casing and stay whole
+        assert_eq!(RosettaStone::to_prose("aB−cD"), "aB minus cD");
+        assert_eq!(
+            RosettaStone::to_prose("userId+offset"),
+            "userId plus offset"
+        );
+        // camelCase is never split, whatever symbol sits next to it
+        assert_eq!(
+            RosettaStone::to_prose("userId∈activeUsers"),
+            "userId in activeUsers"
+        );
+        assert_eq!(RosettaStone::to_prose("∀itemCount"), "for all itemCount");
     }
 
     #[test]
@@ -928,6 +6041,28 @@ mod tests {
         assert!(prose.contains("and"));
     }
 
+    #[test]
+    fn test_temporal_operators() {
+        let (aisp, _, _) = RosettaStone::convert("p until q");
+        assert_eq!(aisp, "p 𝒰 q");
+        let (aisp, _, _) = RosettaStone::convert("henceforth p");
+        assert!(aisp.contains('□'));
+        let (aisp, _, _) = RosettaStone::convert("p weak until q");
+        assert!(aisp.contains('𝒲'));
+        let (aisp, _, _) = RosettaStone::convert("p has held since q");
+        assert_eq!(aisp, "p 𝒮 q");
+
+        // Only temporal phrasings: a bare "next" or causal "since" stays prose
+        let (aisp, _, _) = RosettaStone::convert("the next step is to since we need");
+        assert!(!aisp.contains('○') && !aisp.contains('𝒮'), "{}", aisp);
+
+        assert_eq!(RosettaStone::to_prose("p𝒰q"), "p until q");
+        assert_eq!(RosettaStone::to_prose("p𝒮q"), "p ever since q");
+        assert_eq!(RosettaStone::to_prose("○p"), "in the next state p");
+        assert_eq!(RosettaStone::to_prose("⊖p"), "previously p");
+        assert_eq!(RosettaStone::to_prose("p𝒲q"), "p weak until q");
+    }
+
     #[test]
     fn test_round_trip_simple() {
         let original = "for all x in S";
@@ -957,6 +6092,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_to_prose_relational_operators() {
+        assert_eq!(
+            RosettaStone::to_prose("A↔B"),
+            "A is bidirectionally related to B"
+        );
+        assert_eq!(RosettaStone::to_prose("x ⇔ y"), "x is equivalent to y");
+        assert_eq!(RosettaStone::to_prose("x≡y"), "x is identical to y");
+
+        // Operators without identifier operands keep the primary pattern
+        let prose = RosettaStone::to_prose("u.admin≡⊤");
+        assert!(prose.contains("identical to true"));
+
+        // Relational phrasing converts back to the same symbol
+        let (aisp, _, _) = RosettaStone::convert("A is bidirectionally related to B");
+        assert!(aisp.contains("↔"));
+        let (aisp, _, _) = RosettaStone::convert("x is identical to y");
+        assert_eq!(RosettaStone::to_prose(&aisp), "x is identical to y");
+    }
+
     #[test]
     fn test_semantic_similarity() {
         // Identical texts
@@ -974,6 +6129,22 @@ mod tests {
         assert!(sim < 0.2);
     }
 
+    #[test]
+    fn test_verify_corpus() {
+        let docs = ["for all x in S", "x and y or z", "zebra quagga okapi"];
+        let report = RosettaStone::verify_corpus(&docs, 0.5);
+
+        assert_eq!(report.scores.len(), 3);
+        assert_eq!(report.scores[2], 1.0);
+        assert!(report.passed);
+        assert_eq!(report.worst.len(), 3);
+        assert!(report.worst[0].1 <= report.worst[1].1);
+
+        let report = RosettaStone::verify_corpus(&docs, 1.1);
+        assert!(!report.passed);
+        assert_eq!(report.failures, vec![0, 1, 2]);
+    }
+
     #[test]
     fn test_normalize_whitespace() {
         let result = RosettaStone::normalize_whitespace("  hello   world  ");
@@ -983,6 +6154,117 @@ mod tests {
         assert_eq!(result, "x (a, b)");
     }
 
+    #[test]
+    fn test_normalize_whitespace_preserving_newlines() {
+        let result = RosettaStone::normalize_whitespace_preserving_newlines(
+            "  a   b \n\t c\n\n\n\nd ( e ) ",
+        );
+        assert_eq!(result, "a b\nc\n\nd (e)");
+    }
+
+    #[test]
+    fn test_normalize_whitespace_unbalanced() {
+        // Only matched brackets lose their inner spacing
+        let result = RosettaStone::normalize_whitespace("a ) and ( b");
+        assert_eq!(result, "a ) and ( b");
+        let result = RosettaStone::normalize_whitespace("( a [ b ) c ]");
+        assert_eq!(result, "( a [b ) c]");
+        let result = RosettaStone::normalize_whitespace("f( ( x ) , y");
+        assert_eq!(result, "f( (x), y");
+    }
+
+    #[test]
+    fn test_audit_reverse_coverage() {
+        // Every symbol's primary phrase converts back to it
+        let issues = RosettaStone::audit_reverse_coverage();
+        assert!(issues.is_empty(), "{:#?}", issues);
+    }
+
+    #[test]
+    fn test_glossary_markdown() {
+        let glossary = RosettaStone::glossary(GlossaryFormat::Markdown);
+        for entry in ROSETTA {
+            assert!(
+                glossary.contains(&format!("| `{}` | {} |", entry.symbol, entry.category)),
+                "missing {}",
+                entry.symbol
+            );
+        }
+        // Groups follow the table's section order; unbanded categories have no band
+        let quantifiers = glossary
+            .find("## quantifier (∀ Quantifiers[128-191])")
+            .unwrap();
+        let logic = glossary.find("## logic (Ω Transmuters[0-63])").unwrap();
+        assert!(quantifiers < logic);
+        assert!(glossary.contains("## comparison\n"));
+        assert!(glossary.contains("| `>` | comparison | - | "));
+    }
+
+    #[test]
+    fn test_glossary_json() {
+        let glossary = RosettaStone::glossary(GlossaryFormat::Json);
+        assert!(glossary.starts_with("[\n  {\n    \"category\": \"quantifier\","));
+        assert!(glossary.contains(r#""band": null"#));
+        assert!(glossary
+            .contains(r#""band": {"glyph": "Γ", "name": "Topologics", "start": 64, "end": 127}"#));
+        assert_eq!(json_string("a \"b\"\\"), r#""a \"b\"\\""#);
+
+        #[cfg(feature = "documents")]
+        {
+            let parsed: serde_json::Value = serde_json::from_str(&glossary).unwrap();
+            let entries: usize = parsed
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|group| group["entries"].as_array().unwrap().len())
+                .sum();
+            assert_eq!(entries, ROSETTA.len());
+        }
+    }
+
+    #[test]
+    fn test_unbalanced_parentheses() {
+        assert!(RosettaStone::unbalanced_parentheses("((a) or (b)) and [c]").is_empty());
+        assert_eq!(RosettaStone::unbalanced_parentheses("((a)"), vec![0]);
+        assert_eq!(RosettaStone::unbalanced_parentheses(")("), vec![0, 1]);
+        // A bracket of another kind never closes a parenthesis
+        assert_eq!(RosettaStone::unbalanced_parentheses("(a]"), vec![0]);
+    }
+
+    #[test]
+    fn test_convert_keeps_groups() {
+        let (result, _, _) = RosettaStone::convert("(a or b) and c");
+        assert_eq!(result, "(a∨b)∧c");
+
+        let (result, _, _) = RosettaStone::convert("((a or b) and (c or d)) implies e");
+        assert_eq!(result, "((a∨b)∧(c∨d))⇒e");
+
+        let (result, _, _) = RosettaStone::convert("( a and b ) or c");
+        assert_eq!(result, "(a∧b)∨c");
+
+        // A bound inside a group still binds to its subject
+        let (result, _, _) = RosettaStone::convert("(x is greater than 0) and y");
+        assert_eq!(result, "(x>0)∧y");
+
+        // A grouped condition is negated without doubling its parentheses
+        let (result, _, _) = RosettaStone::convert("if (a and b) then c else d");
+        assert_eq!(result, "(a∧b)⇒c∣¬(a∧b)⇒d");
+
+        // Unbalanced input keeps every parenthesis
+        let (result, _, _) = RosettaStone::convert("a ) and ( b");
+        assert_eq!(result, "a )∧( b");
+    }
+
+    #[test]
+    fn test_document_to_prose_keeps_blocks() {
+        let prose =
+            RosettaStone::document_to_prose("⟦Γ:Rules⟧{\n  ∀x∈S\n}\n\n⟦Λ:Funcs⟧{\n  x≜5\n}");
+        let lines: Vec<_> = prose.lines().collect();
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[1], "for all x in S");
+        assert_eq!(lines[3], "");
+    }
+
     #[test]
     fn test_anti_drift_guarantee() {
         // AISP Anti-drift rule: Mean(s) ≡ Mean_0(s)
@@ -1008,4 +6290,15 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_warmup_is_idempotent() {
+        RosettaStone::warmup();
+        RosettaStone::warmup();
+        assert_eq!(RosettaStone::convert("for all x in S").0, "∀ x∈S");
+        assert_eq!(
+            RosettaStone::pattern_map().get("for all").copied(),
+            Some("∀")
+        );
+    }
 }
@@ -0,0 +1,120 @@
+//! Conversion statistics - aggregate results across a batch of conversions
+//!
+//! `ConversionStats` is an opt-in collector: the caller records each
+//! `ConversionResult` it cares about, then asks which prose was never mapped
+//! and which patterns do the most work, to guide table extensions.
+
+use crate::converter::ConversionResult;
+use std::collections::HashMap;
+
+/// Running totals over recorded conversions
+///
+/// # Example
+/// ```
+/// use rosetta_aisp::{AispConverter, ConversionStats};
+///
+/// let mut stats = ConversionStats::new();
+/// for prose in ["x and y", "a and b or widget", "widget exists"] {
+///     stats.record(&AispConverter::convert(prose, None));
+/// }
+/// assert_eq!(stats.conversions(), 3);
+/// assert_eq!(stats.top_unmapped(1), vec![("widget".to_string(), 2)]);
+/// assert_eq!(stats.pattern_frequency()[0], ("and".to_string(), 2));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConversionStats {
+    conversions: usize,
+    pattern_counts: HashMap<String, usize>,
+    unmapped_counts: HashMap<String, usize>,
+}
+
+impl ConversionStats {
+    /// Create an empty collector
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one conversion's matches and unmapped words to the totals
+    pub fn record(&mut self, result: &ConversionResult) {
+        self.conversions += 1;
+        for (pattern, count) in &result.pattern_counts {
+            *self.pattern_counts.entry(pattern.clone()).or_insert(0) += count;
+        }
+        for word in &result.unmapped {
+            *self.unmapped_counts.entry(word.clone()).or_insert(0) += 1;
+        }
+    }
+
+    /// Number of conversions recorded
+    pub fn conversions(&self) -> usize {
+        self.conversions
+    }
+
+    /// The `n` most frequent unmapped words, most frequent first
+    ///
+    /// A word's count is the number of conversions that left it unmapped;
+    /// ties are broken alphabetically.
+    pub fn top_unmapped(&self, n: usize) -> Vec<(String, usize)> {
+        let mut words = Self::ranked(&self.unmapped_counts);
+        words.truncate(n);
+        words
+    }
+
+    /// Every matched pattern with its total match count, most frequent first
+    pub fn pattern_frequency(&self) -> Vec<(String, usize)> {
+        Self::ranked(&self.pattern_counts)
+    }
+
+    /// Sort counts descending, then by key
+    fn ranked(counts: &HashMap<String, usize>) -> Vec<(String, usize)> {
+        let mut ranked: Vec<_> = counts.iter().map(|(k, v)| (k.clone(), *v)).collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        ranked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::converter::{AispConverter, ConversionOptions, ConversionTier};
+
+    #[test]
+    fn test_record_accumulates() {
+        let mut stats = ConversionStats::new();
+        assert_eq!(stats.conversions(), 0);
+        assert!(stats.top_unmapped(5).is_empty());
+        assert!(stats.pattern_frequency().is_empty());
+
+        stats.record(&AispConverter::convert("for all x in S", None));
+        stats.record(&AispConverter::convert("x in S and y in T", None));
+        assert_eq!(stats.conversions(), 2);
+
+        let frequency = stats.pattern_frequency();
+        assert_eq!(frequency[0], ("in".to_string(), 3));
+        assert!(frequency.contains(&("for all".to_string(), 1)));
+        assert!(frequency.contains(&("and".to_string(), 1)));
+    }
+
+    #[test]
+    fn test_top_unmapped_ordering() {
+        let mut stats = ConversionStats::new();
+        for prose in ["gadget widget", "widget", "sprocket"] {
+            stats.record(&AispConverter::convert(prose, None));
+        }
+        assert_eq!(
+            stats.top_unmapped(2),
+            vec![("widget".to_string(), 2), ("gadget".to_string(), 1)]
+        );
+        assert_eq!(stats.top_unmapped(10).len(), 3);
+    }
+
+    #[test]
+    fn test_pattern_counts_survive_tiers() {
+        let options = ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            ..Default::default()
+        };
+        let result = AispConverter::convert("x and y and z", Some(options));
+        assert_eq!(result.pattern_counts.get("and"), Some(&2));
+    }
+}
@@ -0,0 +1,37 @@
+//! WASM bindings for browser use
+//!
+//! Exposes `convert` and `toProse` to JavaScript via wasm-bindgen.
+
+use crate::converter::{AispConverter, ConversionOptions, ConversionTier};
+use crate::rosetta::RosettaStone;
+use wasm_bindgen::prelude::*;
+
+/// Convert prose to AISP, returning the `ConversionResult` as a JS object
+///
+/// `tier` is one of "minimal", "standard", or "full"; omit it to auto-detect.
+#[wasm_bindgen]
+pub fn convert(prose: &str, tier: Option<String>) -> Result<JsValue, JsValue> {
+    let tier = match tier.as_deref() {
+        None => None,
+        Some("minimal") => Some(ConversionTier::Minimal),
+        Some("standard") => Some(ConversionTier::Standard),
+        Some("full") => Some(ConversionTier::Full),
+        Some(other) => return Err(JsValue::from_str(&format!("unknown tier: {}", other))),
+    };
+
+    let result = AispConverter::convert(
+        prose,
+        Some(ConversionOptions {
+            tier,
+            ..Default::default()
+        }),
+    );
+
+    serde_wasm_bindgen::to_value(&result).map_err(JsValue::from)
+}
+
+/// Convert AISP symbols back to prose
+#[wasm_bindgen(js_name = toProse)]
+pub fn to_prose(aisp: &str) -> String {
+    RosettaStone::to_prose(aisp)
+}
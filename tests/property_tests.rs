@@ -3,7 +3,9 @@
 //! Simulates property-based testing by generating random valid prose
 //! and verifying invariants across the conversion pipeline.
 
-use rosetta_aisp::{AispConverter, ConversionOptions, ConversionTier};
+#![cfg(feature = "documents")]
+
+use rosetta_aisp::{AispConverter, AispDocument, ConversionOptions, ConversionTier, RosettaStone};
 
 /// Simple pseudo-random number generator for reproducibility
 struct PseudoRng {
@@ -150,6 +152,168 @@ fn test_fuzz_conversion() {
     }
 }
 
+#[test]
+fn test_fuzz_ast_round_trip() {
+    let mut rng = PseudoRng::new(13579);
+
+    for _i in 0..100 {
+        let length = (rng.next() % 10) as usize + 3;
+        let prose = generate_random_prose(&mut rng, length);
+        let result = AispConverter::convert(&prose, None);
+
+        // The syntax tree renders back to the exact output
+        assert_eq!(
+            AispDocument::parse(&result.output).to_string(),
+            result.output,
+            "AST round trip failed for: {}",
+            prose
+        );
+    }
+}
+
+#[test]
+fn test_fuzz_parentheses_preserved() {
+    let mut rng = PseudoRng::new(24680);
+    let opts = ConversionOptions {
+        tier: Some(ConversionTier::Minimal),
+        ..Default::default()
+    };
+
+    for _i in 0..100 {
+        // Wrap random clauses in groups, sometimes dropping a closer
+        let mut prose = String::new();
+        let mut open = 0;
+        for _ in 0..(rng.next() % 5) as usize + 2 {
+            if rng.should_do(0.4) {
+                prose.push('(');
+                open += 1;
+            }
+            prose.push_str(&generate_random_prose(&mut rng, 1));
+            if open > 0 && rng.should_do(0.5) {
+                prose.push(')');
+                open -= 1;
+            }
+            prose.push_str(" and ");
+        }
+        prose.push('x');
+        if rng.should_do(0.7) {
+            prose.push_str(&")".repeat(open));
+        }
+
+        let result = AispConverter::convert(&prose, Some(opts.clone()));
+        let count = |s: &str, c: char| s.chars().filter(|&ch| ch == c).count();
+
+        // Conversion may add groups (negated conditions) but never removes a parenthesis
+        assert!(
+            count(&result.output, '(') >= count(&prose, '('),
+            "Lost '(' converting: {}",
+            prose
+        );
+        assert!(
+            count(&result.output, ')') >= count(&prose, ')'),
+            "Lost ')' converting: {}",
+            prose
+        );
+        assert_eq!(
+            result.warnings.is_empty(),
+            RosettaStone::unbalanced_parentheses(&prose).is_empty(),
+            "Warnings disagree for: {}",
+            prose
+        );
+    }
+}
+
+#[test]
+fn test_fuzz_deterministic_structure() {
+    let mut rng = PseudoRng::new(67890);
+    let full = ConversionOptions {
+        tier: Some(ConversionTier::Full),
+        ..Default::default()
+    };
+
+    for _i in 0..50 {
+        let length = (rng.next() % 10) as usize + 3;
+        let prose = generate_random_prose(&mut rng, length);
+
+        let first = AispConverter::convert_deterministic(&prose, Some(full.clone()));
+        let second = AispConverter::convert_deterministic(&prose, Some(full.clone()));
+        assert_eq!(
+            first.output, second.output,
+            "Unstable output for: {}",
+            prose
+        );
+
+        // Header and block order are fixed byte-for-byte
+        assert!(
+            first
+                .output
+                .lines()
+                .next()
+                .unwrap()
+                .ends_with("@1970-01-01"),
+            "Header not pinned for: {}",
+            prose
+        );
+        let blocks: Vec<&str> = first
+            .output
+            .lines()
+            .filter(|line| line.starts_with('⟦'))
+            .collect();
+        assert_eq!(
+            blocks,
+            vec![
+                "⟦Ω:Meta⟧{",
+                "⟦Σ:Types⟧{",
+                "⟦Γ:Rules⟧{",
+                "⟦Λ:Funcs⟧{",
+                "⟦Χ:Errors⟧{",
+                "⟦Ε⟧⟨δ≜0.82;φ≜100;τ≜◊⁺⁺;⊢valid;∎⟩",
+            ],
+            "Unexpected block structure for: {}",
+            prose
+        );
+    }
+}
+
+#[test]
+fn test_deterministic_full_snapshot() {
+    let result = AispConverter::convert_deterministic(
+        "Define x as 5",
+        Some(ConversionOptions {
+            tier: Some(ConversionTier::Full),
+            ..Default::default()
+        }),
+    );
+    let expected = "𝔸5.1.domain@1970-01-01
+γ≔domain.definitions
+ρ≔⟨domain,types,rules⟩
+
+⟦Ω:Meta⟧{
+  domain≜domain
+  version≜1.0.0
+  ∀D∈AISP:Ambig(D)<0.02
+}
+
+⟦Σ:Types⟧{
+  T≜⟨value:Any⟩
+}
+
+⟦Γ:Rules⟧{
+  ∀x:T:⊤
+}
+
+⟦Λ:Funcs⟧{
+  x≜5
+}
+
+⟦Χ:Errors⟧{
+  ∅
+}
+
+⟦Ε⟧⟨δ≜0.82;φ≜100;τ≜◊⁺⁺;⊢valid;∎⟩";
+    assert_eq!(result.output, expected);
+}
+
 #[test]
 fn test_detect_tier_correctness() {
     // Verify that specific keywords trigger appropriate tiers
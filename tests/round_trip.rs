@@ -4,6 +4,8 @@
 //! Verifies that multiple prose → AISP → prose conversions
 //! preserve semantic meaning without drift.
 
+#![cfg(feature = "documents")]
+
 use rosetta_aisp::{AispConverter, RosettaStone};
 
 /// Complex document for testing semantic preservation
@@ -190,12 +192,11 @@ fn test_10_round_trips_minimal_tier() {
 
     for original in test_cases {
         let mut current = original.to_string();
-        let initial_similarity: f64;
 
         // First conversion establishes baseline
         let (aisp, _, _) = RosettaStone::convert(&current);
         let prose = RosettaStone::to_prose(&aisp);
-        initial_similarity = RosettaStone::semantic_similarity(original, &prose);
+        let initial_similarity = RosettaStone::semantic_similarity(original, &prose);
         current = prose;
 
         // Subsequent conversions should maintain stability
@@ -318,6 +319,41 @@ fn test_complex_document_word_preservation() {
     );
 }
 
+#[test]
+fn test_arithmetic_round_trips() {
+    let strip = |s: &str| s.split_whitespace().collect::<String>();
+    let expressions = [
+        "3×4+2",
+        "a−b",
+        "x+y−z",
+        "a−b×c",
+        "avg≔(a+b)÷2",
+        "totalCost≔price×quantity−discount",
+        "x−(−1)",
+    ];
+
+    for aisp in expressions {
+        let prose = RosettaStone::to_prose(aisp);
+        let (back, _, unmapped) = RosettaStone::convert(&prose);
+        assert_eq!(
+            strip(&back),
+            aisp,
+            "'{}' -> '{}' -> '{}'",
+            aisp,
+            prose,
+            back
+        );
+        assert!(
+            !unmapped
+                .iter()
+                .any(|w| ["plus", "minus", "times", "divided"].contains(&w.as_str())),
+            "operator words left unmapped for '{}': {:?}",
+            aisp,
+            unmapped
+        );
+    }
+}
+
 #[test]
 fn test_tier_detection_stability() {
     // Tier detection should be stable across round-trips